@@ -0,0 +1,189 @@
+use std::{borrow::Cow, convert::Infallible, fmt, hash::Hash, str::FromStr};
+
+/// The answer of a puzzle part.
+///
+/// Answers compare by the text that would be submitted, so `Unsigned(5)`, `Signed(5)`
+/// and `Text("5")` are all equal. Rendered answers ignore trailing whitespace on each
+/// line and surrounding blank lines.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    /// A single line answer, e.g. a password or a list of names.
+    Text(String),
+    /// A multi-line drawing, e.g. letters spelled by lit pixels.
+    Rendered(String),
+}
+
+impl Answer {
+    fn canonical(&self) -> Cow<'_, str> {
+        match self {
+            Answer::Signed(n) => Cow::Owned(n.to_string()),
+            Answer::Unsigned(n) => Cow::Owned(n.to_string()),
+            Answer::Text(text) => Cow::Borrowed(text.trim()),
+            Answer::Rendered(text) => Cow::Owned(
+                text.lines()
+                    .map(str::trim_end)
+                    .collect::<Vec<_>>()
+                    .join("\n")
+                    .trim_matches('\n')
+                    .to_owned(),
+            ),
+        }
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        self.canonical().contains('\n')
+    }
+
+    /// Returns the value of numeric answers, or of text answers holding a number.
+    pub fn as_number(&self) -> Option<i128> {
+        match self {
+            Answer::Signed(n) => Some(*n),
+            Answer::Unsigned(n) => i128::try_from(*n).ok(),
+            _ => self.canonical().parse().ok(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => f.pad(&n.to_string()),
+            Answer::Unsigned(n) => f.pad(&n.to_string()),
+            Answer::Text(text) => f.pad(text),
+            Answer::Rendered(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical() == other.canonical()
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.canonical().hash(state);
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Reads a recorded answer, as a number when it is written as one would be, so
+    /// that text such as `007` or `+7` is kept as is.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        Ok(if let Some(n) = parse_exact(trimmed) {
+            Answer::Unsigned(n)
+        } else if let Some(n) = parse_exact(trimmed) {
+            Answer::Signed(n)
+        } else if s.trim_matches('\n').contains('\n') {
+            Answer::Rendered(s.to_owned())
+        } else {
+            Answer::Text(trimmed.to_owned())
+        })
+    }
+}
+
+/// Parses `s` if it is how the value would be printed.
+fn parse_exact<T: FromStr + ToString>(s: &str) -> Option<T> {
+    s.parse().ok().filter(|n: &T| n.to_string() == s)
+}
+
+macro_rules! impl_from_int {
+    ($variant:ident, $wide:ty: $($t:ty),*) => {$(
+        impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::$variant(value as $wide)
+            }
+        }
+
+        impl PartialEq<$t> for Answer {
+            fn eq(&self, other: &$t) -> bool {
+                self.canonical() == other.to_string()
+            }
+        }
+    )*};
+}
+
+impl_from_int!(Signed, i128: i8, i16, i32, i64, i128, isize);
+impl_from_int!(Unsigned, u128: u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        *self == other.parse::<Answer>().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers_compare_by_value() {
+        assert_eq!(Answer::from(5u32), Answer::from(5i64));
+        assert_eq!(Answer::from(-3i32), Answer::Signed(-3));
+        assert_ne!(Answer::from(3u32), Answer::from(-3i32));
+        assert_eq!(Answer::from(u128::MAX), u128::MAX);
+    }
+
+    #[test]
+    fn test_text_and_numbers() {
+        assert_eq!(Answer::from("5086"), 5086);
+        assert_eq!(Answer::from(5086u64), "5086");
+        assert_ne!(Answer::from("abc"), 0);
+    }
+
+    #[test]
+    fn test_as_number() {
+        assert_eq!(Answer::from(-7i64).as_number(), Some(-7));
+        assert_eq!(Answer::from(" 12 ").as_number(), Some(12));
+        assert_eq!(Answer::from(u128::MAX).as_number(), None);
+        assert_eq!(Answer::from("abc").as_number(), None);
+    }
+
+    #[test]
+    fn test_rendered() {
+        let drawn = Answer::Rendered("\n#..#  \n####\n".to_owned());
+        assert_eq!(drawn, Answer::Rendered("#..#\n####".to_owned()));
+        assert!(drawn.is_multiline());
+        assert_eq!("#..#\n####\n".parse::<Answer>().unwrap(), drawn);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert!(matches!("42".parse(), Ok(Answer::Unsigned(42))));
+        assert!(matches!("-42".parse(), Ok(Answer::Signed(-42))));
+        assert!(matches!("ab,cd".parse(), Ok(Answer::Text(_))));
+        let padded = "007".parse::<Answer>().unwrap();
+        assert!(matches!(&padded, Answer::Text(text) if text == "007"));
+        assert_ne!(padded, 7);
+        assert_eq!(padded.to_string(), "007");
+        assert!(matches!("+7".parse(), Ok(Answer::Text(_))));
+        assert!(matches!("-0".parse(), Ok(Answer::Text(_))));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(-7i64).to_string(), "-7");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(format!("{:>4}", Answer::from(12u8)), "  12");
+    }
+}
@@ -0,0 +1,463 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env, fmt, fs, io,
+    path::PathBuf,
+};
+
+use crate::{
+    answer::Answer,
+    days::{self, Entry},
+    error::{Error, Result},
+    guesses::{self, Guess, Verdict},
+    input::{load, Source, INPUT_VAR},
+};
+
+/// Environment variable overriding the path of the recorded answers file.
+pub const ANSWERS_VAR: &str = "AOC_ANSWERS";
+
+/// Known-correct answers, keyed by year, day and part, along with the answers
+/// submitted for each part.
+///
+/// They are stored as TOML, one table per puzzle:
+///
+/// ```toml
+/// [2024.6]
+/// part1 = 5086
+/// part2 = 1770
+/// part2_guesses = [
+///     { answer = 1800, verdict = "too high" },
+///     { answer = 1770, verdict = "right" },
+/// ]
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Answers {
+    records: BTreeMap<(u16, u8), [Option<Answer>; 2]>,
+    guesses: BTreeMap<(u16, u8), [Vec<Guess>; 2]>,
+}
+
+impl Answers {
+    /// Returns [`ANSWERS_VAR`] or the crate's `answers.toml`.
+    pub fn path() -> PathBuf {
+        env::var_os(ANSWERS_VAR)
+            .filter(|v| !v.is_empty())
+            .map_or_else(
+                || PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("answers.toml"),
+                PathBuf::from,
+            )
+    }
+
+    /// Reads the answers file, a missing file having no answers.
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        match fs::read_to_string(&path) {
+            Ok(text) => Self::parse(&text).map_err(|e| {
+                Error::new(format!("{}: {}", path.display(), e.reason))
+                    .at_position(e.line, e.column)
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::new(format!("cannot read {}: {}", path.display(), e))),
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path();
+        fs::write(&path, self.to_string())
+            .map_err(|e| Error::new(format!("cannot write {}: {}", path.display(), e)))
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let table = text.parse::<toml::Table>().map_err(|e| {
+            let error = Error::new(e.message().to_owned());
+            match e.span() {
+                Some(span) => error.at(text, &text[span]),
+                None => error,
+            }
+        })?;
+
+        let mut answers = Answers::default();
+        for (year, days) in &table {
+            let year = year
+                .parse()
+                .map_err(|_| Error::new(format!("invalid year {:?}", year)))?;
+            let days = days
+                .as_table()
+                .ok_or_else(|| Error::new(format!("{} is not a table of days", year)))?;
+            for (day, parts) in days {
+                let day = day
+                    .parse()
+                    .map_err(|_| Error::new(format!("invalid day {}.{:?}", year, day)))?;
+                let parts = parts.as_table().ok_or_else(|| {
+                    Error::new(format!("{}.{} is not a table of parts", year, day))
+                })?;
+                for (key, value) in parts {
+                    let invalid = |what: &str| {
+                        Error::new(format!("{}.{}.{} must be {}", year, day, key, what))
+                    };
+                    match key.as_str() {
+                        "part1" | "part2" => {
+                            let part = if key == "part1" { 1 } else { 2 };
+                            let answer = to_answer(value)
+                                .ok_or_else(|| invalid("an integer or a string"))?;
+                            answers.set(year, day, part, answer)?;
+                        }
+                        "part1_guesses" | "part2_guesses" => {
+                            let part = if key == "part1_guesses" { 1 } else { 2 };
+                            let guesses = value
+                                .as_array()
+                                .ok_or_else(|| invalid("an array of guesses"))?;
+                            for guess in guesses {
+                                let guess = guess
+                                    .as_table()
+                                    .and_then(|g| {
+                                        Some(Guess {
+                                            answer: to_answer(g.get("answer")?)?,
+                                            verdict: g.get("verdict")?.as_str()?.parse().ok()?,
+                                        })
+                                    })
+                                    .ok_or_else(|| {
+                                        invalid("an array of { answer, verdict } tables")
+                                    })?;
+                                answers.guesses.entry((year, day)).or_default()[part - 1]
+                                    .push(guess);
+                            }
+                        }
+                        _ => {
+                            return Err(Error::new(format!("unknown key {}.{}.{}", year, day, key)))
+                        }
+                    }
+                }
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Returns the recorded answer of a part, `None` as well for a part other than
+    /// 1 or 2.
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&Answer> {
+        let slot = slot(part).ok()?;
+        self.records.get(&(year, day))?[slot].as_ref()
+    }
+
+    pub fn set(&mut self, year: u16, day: u8, part: u8, answer: Answer) -> Result<()> {
+        self.records.entry((year, day)).or_default()[slot(part)?] = Some(answer);
+        Ok(())
+    }
+
+    /// Returns the answers submitted for a part, oldest first.
+    pub fn guesses(&self, year: u16, day: u8, part: u8) -> Result<&[Guess]> {
+        let slot = slot(part)?;
+        Ok(self
+            .guesses
+            .get(&(year, day))
+            .map_or(&[], |parts| &parts[slot]))
+    }
+
+    /// Logs a submitted answer, recording it as the answer of the part if right.
+    pub fn add_guess(&mut self, year: u16, day: u8, part: u8, guess: Guess) -> Result<()> {
+        let slot = slot(part)?;
+        if guess.verdict == Verdict::Right {
+            self.set(year, day, part, guess.answer.clone())?;
+        }
+        self.guesses.entry((year, day)).or_default()[slot].push(guess);
+        Ok(())
+    }
+
+    /// Fails if submitting `answer` is pointless: the part is already solved, or the
+    /// answer was already rejected or lies outside the bounds of earlier guesses.
+    pub fn check_guess(&self, year: u16, day: u8, part: u8, answer: &Answer) -> Result<()> {
+        if let Some(recorded) = self.get(year, day, part) {
+            return Err(Error::new(format!(
+                "part {} is already solved, the answer is {}",
+                part, recorded
+            )));
+        }
+        guesses::check(self.guesses(year, day, part)?, answer)
+    }
+}
+
+/// Returns the index of a part in the records of a puzzle.
+fn slot(part: u8) -> Result<usize> {
+    match part {
+        1 | 2 => Ok(part as usize - 1),
+        _ => Err(Error::new(format!("no part {}, expected 1 or 2", part))),
+    }
+}
+
+pub(crate) fn to_answer(value: &toml::Value) -> Option<Answer> {
+    match value {
+        toml::Value::Integer(n) => Some(Answer::from(*n)),
+        toml::Value::String(s) => Some(s.parse().unwrap()),
+        _ => None,
+    }
+}
+
+pub(crate) fn to_value(answer: &Answer) -> toml::Value {
+    match answer {
+        Answer::Signed(n) => i64::try_from(*n).ok().map(toml::Value::from),
+        Answer::Unsigned(n) => i64::try_from(*n).ok().map(toml::Value::from),
+        _ => None,
+    }
+    .unwrap_or_else(|| toml::Value::from(answer.to_string()))
+}
+
+impl fmt::Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let puzzles = self
+            .records
+            .keys()
+            .chain(self.guesses.keys())
+            .collect::<BTreeSet<_>>();
+        for (i, &(year, day)) in puzzles.into_iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "[{}.{}]", year, day)?;
+            for part in 1..=2 {
+                if let Some(answer) = self.get(year, day, part) {
+                    writeln!(f, "part{} = {}", part, to_value(answer))?;
+                }
+            }
+            for part in 1..=2 {
+                let guesses = self.guesses(year, day, part).unwrap_or_default();
+                if guesses.is_empty() {
+                    continue;
+                }
+                writeln!(f, "part{}_guesses = [", part)?;
+                for guess in guesses {
+                    writeln!(
+                        f,
+                        "    {{ answer = {}, verdict = {} }},",
+                        to_value(&guess.answer),
+                        toml::Value::from(guess.verdict.to_string())
+                    )?;
+                }
+                writeln!(f, "]")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of checking a solution against the recorded answer of a part.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Match,
+    Mismatch {
+        expected: Answer,
+    },
+    /// No answer recorded for the part yet.
+    Missing,
+    /// The input could not be read or the solution failed.
+    Failed(String),
+}
+
+/// A row of the verification report.
+#[derive(Debug, Clone)]
+pub struct Verification {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub status: Status,
+}
+
+/// Runs both parts of a day on its input and compares them with the recorded answers.
+pub fn verify(entry: &Entry, answers: &Answers, source: &Source) -> Vec<Verification> {
+    let input = load(entry.year, entry.day, source)
+        .map_err(Error::from)
+        .and_then(|raw| entry.parse(&raw));
+
+    (1..=2)
+        .map(|part| {
+            let answer = input.as_ref().map_err(|e| e.to_string()).and_then(|input| {
+                match part {
+                    1 => entry.part1(input.as_ref()),
+                    _ => entry.part2(input.as_ref()),
+                }
+                .map_err(|e| e.to_string())
+            });
+            let status = match (&answer, answers.get(entry.year, entry.day, part)) {
+                (Err(e), _) => Status::Failed(e.clone()),
+                (Ok(_), None) => Status::Missing,
+                (Ok(answer), Some(expected)) if answer == expected => Status::Match,
+                (Ok(_), Some(expected)) => Status::Mismatch {
+                    expected: expected.clone(),
+                },
+            };
+            Verification {
+                year: entry.year,
+                day: entry.day,
+                part,
+                answer: answer.ok(),
+                status,
+            }
+        })
+        .collect()
+}
+
+/// Runs a part of a day on its input, to record or submit the answer.
+///
+/// Fails when `source` is not [`Source::Default`], as the answer to another input,
+/// such as an example, must not be taken for the day's.
+pub fn compute(entry: &Entry, part: u8, source: &Source) -> Result<Answer> {
+    if *source != Source::Default {
+        return Err(Error::new(format!(
+            "{} is not the puzzle's input, unset {} to record or submit an answer",
+            source, INPUT_VAR
+        ))
+        .in_puzzle(entry.year, entry.day));
+    }
+    let input = entry.parse(&load(entry.year, entry.day, source)?)?;
+    match part {
+        1 => entry.part1(input.as_ref()),
+        _ => entry.part2(input.as_ref()),
+    }
+}
+
+/// Verifies a day against `answers.toml`, failing on a part whose answer differs from
+/// the recorded one or cannot be computed.
+///
+/// Parts without a recorded answer are skipped, so that a new day passes until its
+/// answers are recorded.
+pub fn check(year: u16, day: u8) -> Result<()> {
+    let entry = days::get(year, day)
+        .ok_or_else(|| Error::new("no registered solution").in_puzzle(year, day))?;
+    let answers = Answers::load()?;
+    for row in verify(entry, &answers, &Source::Default) {
+        let reason = match row.status {
+            Status::Match | Status::Missing => continue,
+            Status::Mismatch { expected } => format!(
+                "part {}: expected {}, got {}",
+                row.part,
+                expected,
+                row.answer.unwrap()
+            ),
+            Status::Failed(e) => format!("part {}: {}", row.part, e),
+        };
+        return Err(Error::new(reason).in_puzzle(year, day));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "[2023.1]\npart1 = 55017\npart2 = \"abc\"\n\n[2024.6]\npart2 = 1770\n";
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(TEXT).unwrap();
+        assert_eq!(answers.get(2023, 1, 1), Some(&Answer::from(55017)));
+        assert_eq!(answers.get(2023, 1, 2), Some(&Answer::from("abc")));
+        assert_eq!(answers.get(2024, 6, 1), None);
+        assert_eq!(answers.get(2024, 7, 1), None);
+    }
+
+    #[test]
+    fn test_invalid_part() {
+        let mut answers = Answers::parse(TEXT).unwrap();
+        assert_eq!(answers.get(2023, 1, 0), None);
+        assert_eq!(answers.get(2023, 1, 3), None);
+        let error = answers.set(2023, 1, 0, Answer::from(1)).unwrap_err();
+        assert_eq!(error.reason, "no part 0, expected 1 or 2");
+        assert!(answers.guesses(2023, 1, 3).is_err());
+        let guess = Guess {
+            answer: Answer::from(1),
+            verdict: Verdict::Right,
+        };
+        assert!(answers.add_guess(2023, 1, 3, guess).is_err());
+        assert_eq!(answers, Answers::parse(TEXT).unwrap());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::parse(TEXT).unwrap();
+        assert_eq!(answers.to_string(), TEXT);
+        answers.set(2024, 6, 1, Answer::from(u128::MAX)).unwrap();
+        answers
+            .set(2022, 10, 2, Answer::Rendered("#.\n.#\n".to_owned()))
+            .unwrap();
+        assert_eq!(Answers::parse(&answers.to_string()).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Answers::parse("[2023.1]\npart3 = 1\n").unwrap_err();
+        assert_eq!(error.reason, "unknown key 2023.1.part3");
+        let error = Answers::parse("[2023.1]\npart1 = \n").unwrap_err();
+        assert_eq!(error.line, Some(2));
+    }
+
+    #[test]
+    fn test_guesses() {
+        let mut answers = Answers::parse(TEXT).unwrap();
+        let guess = |answer: i64, verdict| Guess {
+            answer: Answer::from(answer),
+            verdict,
+        };
+        answers
+            .add_guess(2024, 6, 1, guess(5100, Verdict::TooHigh))
+            .unwrap();
+        answers
+            .add_guess(2024, 7, 2, guess(-3, Verdict::Wrong))
+            .unwrap();
+        assert!(answers.check_guess(2024, 6, 1, &Answer::from(5086)).is_ok());
+        assert!(answers
+            .check_guess(2024, 6, 1, &Answer::from(5100))
+            .is_err());
+        assert!(answers.check_guess(2024, 6, 2, &Answer::from(5)).is_err());
+
+        answers
+            .add_guess(2024, 6, 1, guess(5086, Verdict::Right))
+            .unwrap();
+        assert_eq!(answers.get(2024, 6, 1), Some(&Answer::from(5086)));
+        assert_eq!(answers.guesses(2024, 6, 1).unwrap().len(), 2);
+        assert_eq!(answers.guesses(2023, 1, 1).unwrap(), []);
+
+        let text = answers.to_string();
+        assert!(text.contains(
+            "[2024.6]\npart1 = 5086\npart2 = 1770\npart1_guesses = [\n    { answer = 5100, verdict = \"too high\" },\n"
+        ));
+        assert!(text.ends_with(
+            "[2024.7]\npart2_guesses = [\n    { answer = -3, verdict = \"wrong\" },\n]\n"
+        ));
+        assert_eq!(Answers::parse(&text).unwrap(), answers);
+
+        let error = Answers::parse("[2024.6]\npart1_guesses = [{ answer = 1 }]\n").unwrap_err();
+        assert_eq!(
+            error.reason,
+            "2024.6.part1_guesses must be an array of { answer, verdict } tables"
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let entry = days::get(2024, 1).unwrap();
+        let mut answers = Answers::default();
+        answers.set(2024, 1, 1, Answer::from(11)).unwrap();
+        answers.set(2024, 1, 2, Answer::from(30)).unwrap();
+        let source = Source::File(crate::examples::example_path(2024, 1, "example"));
+        let rows = verify(entry, &answers, &source);
+        assert_eq!(rows[0].status, Status::Match);
+        assert_eq!(
+            rows[1].status,
+            Status::Mismatch {
+                expected: Answer::from(30)
+            }
+        );
+        let rows = verify(entry, &Answers::default(), &source);
+        assert_eq!(rows[0].status, Status::Missing);
+    }
+
+    #[test]
+    fn test_compute_own_input_only() {
+        let entry = days::get(2024, 1).unwrap();
+        let source = Source::File(crate::examples::example_path(2024, 1, "example"));
+        let error = compute(entry, 1, &source).unwrap_err();
+        assert_eq!(error.puzzle, Some((2024, 1)));
+        assert!(error.reason.contains(INPUT_VAR));
+        assert!(compute(entry, 1, &Source::Stdin).is_err());
+        assert_ne!(compute(entry, 1, &Source::Default).unwrap(), 11);
+    }
+}
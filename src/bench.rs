@@ -0,0 +1,265 @@
+use std::{
+    fmt, fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    days::Entry,
+    error::{Error, Result},
+};
+
+/// Step of a solution timed on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2",
+        })
+    }
+}
+
+/// Timings of one phase over several runs, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub phase: Phase,
+    pub runs: usize,
+    pub min: u64,
+    pub median: u64,
+    pub mean: u64,
+}
+
+impl Measurement {
+    /// Summarises the samples of a phase, `None` if there are none.
+    fn new(entry: &Entry, phase: Phase, samples: &mut [Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort();
+        let nanos = |n: u128| u64::try_from(n).unwrap_or(u64::MAX);
+        let total = samples.iter().map(Duration::as_nanos).sum::<u128>();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+        Some(Measurement {
+            year: entry.year,
+            day: entry.day,
+            phase,
+            runs: samples.len(),
+            min: nanos(samples[0].as_nanos()),
+            median: nanos(median.as_nanos()),
+            mean: nanos(total / samples.len() as u128),
+        })
+    }
+
+    fn key(&self) -> (u16, u8, Phase) {
+        (self.year, self.day, self.phase)
+    }
+}
+
+/// Runs the parser and both parts of a day `runs` times each on `raw`.
+///
+/// The parts are timed on a single parsed input, so cloning it is not measured.
+pub fn measure(entry: &Entry, raw: &str, runs: usize) -> Result<Vec<Measurement>> {
+    if runs == 0 {
+        return Err(Error::new("at least one run is needed"));
+    }
+    let sample = |f: &mut dyn FnMut() -> Result<()>| -> Result<Vec<Duration>> {
+        (0..runs)
+            .map(|_| {
+                let start = Instant::now();
+                f().map(|()| start.elapsed())
+            })
+            .collect()
+    };
+
+    let input = entry.parse(raw)?;
+    let parse = sample(&mut || entry.parse(raw).map(drop))?;
+    let part1 = sample(&mut || entry.part1(input.as_ref()).map(drop))?;
+    let part2 = sample(&mut || entry.part2(input.as_ref()).map(drop))?;
+
+    [
+        (Phase::Parse, parse),
+        (Phase::Part1, part1),
+        (Phase::Part2, part2),
+    ]
+    .into_iter()
+    .map(|(phase, mut samples)| {
+        Measurement::new(entry, phase, &mut samples)
+            .ok_or_else(|| Error::new("at least one run is needed"))
+    })
+    .collect()
+}
+
+/// Timings of a benchmark session, saved as JSON to serve as a later baseline.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub measurements: Vec<Measurement>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = fs::read_to_string(path)
+            .map_err(|e| Error::new(format!("cannot read {}: {}", path.display(), e)))?;
+        serde_json::from_str(&text).map_err(|e| {
+            Error::new(format!("{}: {}", path.display(), e))
+                .at_position(Some(e.line()), Some(e.column()))
+        })
+    }
+
+    /// Writes the report as JSON, or as CSV when the path ends in `.csv`.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let text = match path.extension() {
+            Some(extension) if extension == "csv" => self.to_csv(),
+            _ => serde_json::to_string_pretty(self).expect("reports serialize to JSON") + "\n",
+        };
+        fs::write(path, text)
+            .map_err(|e| Error::new(format!("cannot write {}: {}", path.display(), e)))
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("year,day,phase,runs,min_ns,median_ns,mean_ns\n");
+        for m in &self.measurements {
+            csv += &format!(
+                "{},{},{},{},{},{},{}\n",
+                m.year, m.day, m.phase, m.runs, m.min, m.median, m.mean
+            );
+        }
+        csv
+    }
+
+    /// Pairs each measurement with the baseline one of the same day and phase.
+    ///
+    /// A phase regresses when its median grows by more than `threshold`, a
+    /// fraction of the baseline median.
+    pub fn compare(&self, baseline: &Report, threshold: f64) -> Vec<Comparison> {
+        self.measurements
+            .iter()
+            .filter_map(|current| {
+                let baseline = baseline
+                    .measurements
+                    .iter()
+                    .find(|b| b.key() == current.key())?;
+                let ratio = current.median as f64 / baseline.median.max(1) as f64;
+                Some(Comparison {
+                    current: current.clone(),
+                    baseline: baseline.clone(),
+                    ratio,
+                    regression: ratio > 1.0 + threshold,
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub current: Measurement,
+    pub baseline: Measurement,
+    /// Current median over the baseline one.
+    pub ratio: f64,
+    pub regression: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    fn measurement(phase: Phase, median: u64) -> Measurement {
+        Measurement {
+            year: 2024,
+            day: 1,
+            phase,
+            runs: 3,
+            min: median,
+            median,
+            mean: median,
+        }
+    }
+
+    #[test]
+    fn test_statistics() {
+        let entry = days::get(2024, 1).unwrap();
+        let mut samples = [7, 1, 4, 2].map(Duration::from_nanos);
+        let m = Measurement::new(entry, Phase::Part1, &mut samples).unwrap();
+        assert_eq!((m.runs, m.min, m.median, m.mean), (4, 1, 3, 3));
+
+        let mut samples = [9, 1, 5].map(Duration::from_nanos);
+        let m = Measurement::new(entry, Phase::Part1, &mut samples).unwrap();
+        assert_eq!((m.min, m.median, m.mean), (1, 5, 5));
+
+        assert!(Measurement::new(entry, Phase::Part1, &mut []).is_none());
+    }
+
+    #[test]
+    fn test_measure() {
+        let entry = days::get(2024, 1).unwrap();
+        let report = measure(entry, "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n", 3).unwrap();
+        let phases = report.iter().map(|m| m.phase).collect::<Vec<_>>();
+        assert_eq!(phases, [Phase::Parse, Phase::Part1, Phase::Part2]);
+        assert!(report.iter().all(|m| m.runs == 3 && m.min <= m.median));
+
+        assert!(measure(entry, "3   x\n", 3).is_err());
+        assert!(measure(entry, "3   4\n", 0).is_err());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let report = Report {
+            measurements: vec![measurement(Phase::Parse, 10), measurement(Phase::Part2, 20)],
+        };
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(json.contains("\"phase\":\"part2\""));
+        assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), report);
+    }
+
+    #[test]
+    fn test_csv() {
+        let report = Report {
+            measurements: vec![measurement(Phase::Part1, 10)],
+        };
+        assert_eq!(
+            report.to_csv(),
+            "year,day,phase,runs,min_ns,median_ns,mean_ns\n2024,1,part1,3,10,10,10\n"
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Report {
+            measurements: vec![
+                measurement(Phase::Parse, 100),
+                measurement(Phase::Part1, 100),
+            ],
+        };
+        let current = Report {
+            measurements: vec![
+                measurement(Phase::Parse, 105),
+                measurement(Phase::Part1, 150),
+                measurement(Phase::Part2, 100),
+            ],
+        };
+        let comparisons = current.compare(&baseline, 0.1);
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regression);
+        assert!(comparisons[1].regression);
+        assert_eq!(comparisons[1].ratio, 1.5);
+    }
+}
@@ -0,0 +1,514 @@
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use advent_of_code::{
+    answer::Answer,
+    answers::{self, Answers, Status},
+    bench::{self, Report},
+    client::{Client, Hint, Outcome},
+    days::{self, Entry, REGISTRY},
+    error::{Error, Result},
+    examples,
+    guesses::{Bounds, Guess, Verdict},
+    input::{self, Source},
+    puzzle,
+    scaffold::{self, Template},
+};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs a single day, a whole year or, with --all, every registered puzzle
+    Run {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// Runs every registered puzzle
+        #[arg(long, conflicts_with_all = ["year", "day"])]
+        all: bool,
+        /// Runs only the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Reads the puzzle input from this file, or stdin for `-`, instead of
+        /// inputs/<year>/<day>.txt [env: AOC_INPUT, only for a single day]
+        #[arg(long, requires = "day")]
+        input: Option<Source>,
+    },
+    /// Downloads the input of a day into inputs/<year>/<day>.txt unless already there,
+    /// and its description into inputs/<year>/<day>.html
+    Fetch { year: u16, day: u8 },
+    /// Saves the examples of the saved description of a day and their expected answers
+    Extract { year: u16, day: u8 },
+    /// Submits the answer of a part, computing it when not given, unless earlier guesses
+    /// rule it out; logs the verdict and records the answer if right
+    Submit {
+        year: u16,
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: Option<String>,
+    },
+    /// Times the parser and both parts of a day, a year or, with --all, every puzzle
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+        #[arg(long, conflicts_with_all = ["year", "day"])]
+        all: bool,
+        /// Number of timed runs of each phase
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+        /// Writes the report to this file, as CSV if it ends in .csv and JSON otherwise
+        #[arg(long)]
+        report: Option<PathBuf>,
+        /// Compares against a JSON report saved by an earlier run
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Fraction by which a median may grow before it counts as a regression
+        #[arg(long, default_value_t = 0.1)]
+        threshold: f64,
+    },
+    /// Lists the registered puzzles
+    List,
+    /// Checks every registered puzzle, a year or a day against the recorded answers
+    Verify { year: Option<u16>, day: Option<u8> },
+    /// Records the confirmed answer of a part, computing it when not given
+    Record {
+        year: u16,
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: Option<String>,
+    },
+    /// Creates the solution, test and input files of a new day and registers it
+    New {
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Shape of the generated parser: lines, grid or sections
+        #[arg(long, default_value_t)]
+        template: Template,
+    },
+}
+
+fn main() -> ExitCode {
+    let result = match Cli::parse().command {
+        Command::Run {
+            year,
+            day,
+            all,
+            part,
+            input,
+        } => select(all, year, day).and_then(|entries| {
+            let source = choose_source(&entries, input)?;
+            run_all(&entries, &source, part)
+        }),
+        Command::Fetch { year, day } => fetch(year, day),
+        Command::Extract { year, day } => extract(year, day),
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        } => submit(year, day, part, answer),
+        Command::Bench {
+            year,
+            day,
+            all,
+            runs,
+            report,
+            baseline,
+            threshold,
+        } => select(all, year, day).and_then(|entries| {
+            bench(
+                &entries,
+                runs as usize,
+                report.as_deref(),
+                baseline.as_deref(),
+                threshold,
+            )
+        }),
+        Command::List => {
+            list();
+            Ok(())
+        }
+        Command::Verify { year, day } => select(year.is_none(), year, day).and_then(|e| verify(&e)),
+        Command::Record {
+            year,
+            day,
+            part,
+            answer,
+        } => record(year, day, part, answer),
+        Command::New {
+            year,
+            day,
+            template,
+        } => new(year, day, template),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Picks every puzzle, those of a year or a single day.
+fn select(all: bool, year: Option<u16>, day: Option<u8>) -> Result<Vec<&'static Entry>> {
+    let entries: Vec<&Entry> = match (all, year, day) {
+        (true, _, _) => REGISTRY.iter().collect(),
+        (false, Some(year), Some(day)) => days::get(year, day).into_iter().collect(),
+        (false, Some(year), None) => days::of_year(year).collect(),
+        (false, None, _) => return Err(Error::new("Specify a year and a day, or use --all")),
+    };
+    if entries.is_empty() {
+        return Err(Error::new(
+            "No solution registered for the requested puzzle",
+        ));
+    }
+    Ok(entries)
+}
+
+/// Picks where the inputs of the selected puzzles are read from, refusing to feed
+/// the file, or stdin, given by --input to several puzzles.
+///
+/// [`Source::from_env`] only applies to a single day, the others reading their own.
+fn choose_source(entries: &[&Entry], input: Option<Source>) -> Result<Source> {
+    match input {
+        Some(source) if source != Source::Default && entries.len() > 1 => Err(Error::new(format!(
+            "Cannot read the input of {} puzzles from {}, select a single day",
+            entries.len(),
+            source
+        ))),
+        Some(source) => Ok(source),
+        None if entries.len() > 1 => Ok(Source::Default),
+        None => Ok(Source::from_env()),
+    }
+}
+
+fn run_all(entries: &[&Entry], source: &Source, part: Option<u8>) -> Result<()> {
+    let mut failed = 0;
+    for entry in entries {
+        if let Err(error) = run(entry, source, part) {
+            eprintln!("{}", error);
+            failed += 1;
+        }
+    }
+    match failed {
+        0 => Ok(()),
+        _ => Err(Error::new(format!("{} puzzles failed", failed))),
+    }
+}
+
+fn bench(
+    entries: &[&Entry],
+    runs: usize,
+    report_path: Option<&Path>,
+    baseline: Option<&Path>,
+    threshold: f64,
+) -> Result<()> {
+    let mut report = Report::default();
+    println!(
+        "{:<11}  {:<5}  {:>12}  {:>12}  {:>12}",
+        "puzzle", "phase", "min", "median", "mean"
+    );
+    for entry in entries {
+        let raw = input::load(entry.year, entry.day, &Source::Default)?;
+        for m in
+            bench::measure(entry, &raw, runs).map_err(|e| e.in_puzzle(entry.year, entry.day))?
+        {
+            println!(
+                "{}  {:<5}  {:>12?}  {:>12?}  {:>12?}",
+                label(entry),
+                m.phase,
+                Duration::from_nanos(m.min),
+                Duration::from_nanos(m.median),
+                Duration::from_nanos(m.mean)
+            );
+            report.measurements.push(m);
+        }
+    }
+    if let Some(path) = report_path {
+        report.save(path)?;
+    }
+
+    let Some(baseline) = baseline else {
+        return Ok(());
+    };
+    let comparisons = report.compare(&Report::load(baseline)?, threshold);
+    println!();
+    println!(
+        "{:<11}  {:<5}  {:>12}  {:>12}  {:>7}",
+        "puzzle", "phase", "baseline", "median", "change"
+    );
+    for c in &comparisons {
+        println!(
+            "{} day {:02}  {:<5}  {:>12?}  {:>12?}  {:>+6.1}%{}",
+            c.current.year,
+            c.current.day,
+            c.current.phase,
+            Duration::from_nanos(c.baseline.median),
+            Duration::from_nanos(c.current.median),
+            (c.ratio - 1.0) * 100.0,
+            if c.regression { "  REGRESSION" } else { "" }
+        );
+    }
+    match comparisons.iter().filter(|c| c.regression).count() {
+        0 => Ok(()),
+        n => Err(Error::new(format!(
+            "{} phases regressed by more than {:.0}%",
+            n,
+            threshold * 100.0
+        ))),
+    }
+}
+
+fn new(year: u16, day: u8, template: Template) -> Result<()> {
+    let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+    for path in scaffold::scaffold(root, year, day, template)? {
+        println!(
+            "Created {}",
+            path.strip_prefix(root).unwrap_or(&path).display()
+        );
+    }
+    Ok(())
+}
+
+fn list() {
+    let mut years = REGISTRY.iter().map(|e| e.year).collect::<Vec<_>>();
+    years.dedup();
+    for year in years {
+        let days = days::of_year(year)
+            .map(|e| e.day.to_string())
+            .collect::<Vec<_>>();
+        println!("{}: {}", year, days.join(" "));
+    }
+}
+
+fn verify(entries: &[&Entry]) -> Result<()> {
+    let answers = Answers::load()?;
+    let (mut matching, mut mismatching, mut missing, mut failed) = (0, 0, 0, 0);
+
+    println!(
+        "{:<4}  {:>3}  {:>4}  {:<8}  {:>20}  {:>20}",
+        "year", "day", "part", "status", "answer", "expected"
+    );
+    for entry in entries {
+        for row in answers::verify(entry, &answers, &Source::Default) {
+            let answer = row
+                .answer
+                .as_ref()
+                .map(|a| a.to_string())
+                .unwrap_or_default();
+            let (status, expected) = match &row.status {
+                Status::Match => {
+                    matching += 1;
+                    ("ok", String::new())
+                }
+                Status::Mismatch { expected } => {
+                    mismatching += 1;
+                    ("MISMATCH", expected.to_string())
+                }
+                Status::Missing => {
+                    missing += 1;
+                    ("missing", String::new())
+                }
+                Status::Failed(reason) => {
+                    failed += 1;
+                    ("FAILED", reason.clone())
+                }
+            };
+            println!(
+                "{:<4}  {:>3}  {:>4}  {:<8}  {:>20}  {:>20}",
+                row.year, row.day, row.part, status, answer, expected
+            );
+        }
+    }
+    println!(
+        "{} matching, {} mismatching, {} missing, {} failed",
+        matching, mismatching, missing, failed
+    );
+
+    if mismatching + failed > 0 {
+        Err(Error::new("Some answers do not match the recorded ones"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Records a confirmed answer, running the solution when it is not given.
+fn record(year: u16, day: u8, part: u8, answer: Option<String>) -> Result<()> {
+    let answer = match answer {
+        Some(answer) => answer.parse().unwrap(),
+        None => compute(year, day, part)?,
+    };
+
+    let mut answers = Answers::load()?;
+    if let Some(previous) = answers.get(year, day, part) {
+        if *previous != answer {
+            eprintln!("Replacing the recorded answer {}", previous);
+        }
+    }
+    println!("{} day {:02} part {}: {}", year, day, part, answer);
+    answers.set(year, day, part, answer)?;
+    answers.save()
+}
+
+/// Runs a part of a day on its input, refusing the one set by [`Source::from_env`].
+fn compute(year: u16, day: u8, part: u8) -> Result<Answer> {
+    let entry = days::get(year, day)
+        .ok_or_else(|| Error::new("No solution registered for the requested puzzle"))?;
+    answers::compute(entry, part, &Source::from_env())
+}
+
+fn fetch(year: u16, day: u8) -> Result<()> {
+    let mut client = Client::from_env()?;
+    let input = client.input(year, day)?;
+    println!(
+        "{} day {:02}: {} lines in {}",
+        year,
+        day,
+        input.lines().count(),
+        input::path_in(&client.inputs, year, day).display()
+    );
+    client.description(year, day)?;
+    println!(
+        "Saved the description, run `aoc extract {} {}` to save its examples",
+        year, day
+    );
+    Ok(())
+}
+
+fn extract(year: u16, day: u8) -> Result<()> {
+    let found = puzzle::extract(&puzzle::load_description(year, day)?);
+    if found.is_empty() {
+        return Err(Error::new("No example found in the description"));
+    }
+    for example in &found {
+        let expected = [(1, &example.part1), (2, &example.part2)]
+            .into_iter()
+            .filter_map(|(part, answer)| Some(format!("part{} = {}", part, answer.as_ref()?)))
+            .collect::<Vec<_>>();
+        println!(
+            "{}: {} lines, {}",
+            example.name,
+            example.input.lines().count(),
+            expected.join(", ")
+        );
+    }
+    for path in examples::save(year, day, &found)? {
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn submit(year: u16, day: u8, part: u8, answer: Option<String>) -> Result<()> {
+    let answer = match answer {
+        Some(answer) => answer.parse().unwrap(),
+        None => compute(year, day, part)?,
+    };
+
+    let mut answers = Answers::load()?;
+    let guesses = answers.guesses(year, day, part)?;
+    if !guesses.is_empty() && answers.get(year, day, part).is_none() {
+        let rejected = guesses
+            .iter()
+            .map(|g| format!("{} ({})", g.answer, g.verdict))
+            .collect::<Vec<_>>();
+        println!("Already submitted: {}", rejected.join(", "));
+        println!("Feasible: {}", Bounds::of(guesses));
+    }
+    answers.check_guess(year, day, part, &answer)?;
+    println!(
+        "{} day {:02} part {}: submitting {}",
+        year, day, part, answer
+    );
+
+    let outcome = Client::from_env()?.submit(year, day, part, &answer)?;
+    if let Some(verdict) = Verdict::of(&outcome) {
+        answers.add_guess(year, day, part, Guess { answer, verdict })?;
+        answers.save()?;
+    }
+    match outcome {
+        Outcome::Correct => {
+            println!("Right answer, recorded it");
+            Ok(())
+        }
+        Outcome::Incorrect { hint, wait } => {
+            let hint = match hint {
+                Some(Hint::TooHigh) => ", too high",
+                Some(Hint::TooLow) => ", too low",
+                None => "",
+            };
+            let wait = wait
+                .map(|w| format!(", wait {}s before the next one", w.as_secs()))
+                .unwrap_or_default();
+            Err(Error::new(format!("Wrong answer{}{}", hint, wait)))
+        }
+        Outcome::TooSoon { wait } => Err(Error::new(format!(
+            "Answered too recently, wait {}s",
+            wait.as_secs()
+        ))),
+        Outcome::WrongLevel => Err(Error::new("The part is already solved or still locked")),
+    }
+}
+
+fn label(entry: &Entry) -> String {
+    format!("{} day {:02}", entry.year, entry.day)
+}
+
+fn run(entry: &Entry, source: &Source, part: Option<u8>) -> Result<()> {
+    let raw = input::load(entry.year, entry.day, source)?;
+
+    let (input, elapsed) = timed(|| entry.parse(&raw));
+    let input = input?;
+    println!("{}  parse   {:>20}  {:>12?}", label(entry), "", elapsed);
+
+    if part.is_none_or(|p| p == 1) {
+        let (answer, elapsed) = timed(|| entry.part1(input.as_ref()));
+        let answer = answer?;
+        print_part(entry, 1, &answer, elapsed);
+    }
+    if part.is_none_or(|p| p == 2) {
+        let (answer, elapsed) = timed(|| entry.part2(input.as_ref()));
+        let answer = answer?;
+        print_part(entry, 2, &answer, elapsed);
+    }
+    Ok(())
+}
+
+fn print_part(entry: &Entry, part: u8, answer: &Answer, elapsed: Duration) {
+    if answer.is_multiline() {
+        println!(
+            "{}  part {}  {:>20}  {:>12?}",
+            label(entry),
+            part,
+            "",
+            elapsed
+        );
+        println!("{}", answer);
+    } else {
+        println!(
+            "{}  part {}  {:>20}  {:>12?}",
+            label(entry),
+            part,
+            answer,
+            elapsed
+        );
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
@@ -0,0 +1,503 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{
+    answer::Answer,
+    answers::Answers,
+    error::{Error, Result},
+    input, puzzle,
+};
+
+#[cfg(test)]
+mod stub;
+
+/// Environment variable holding the session token of the puzzle site.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable overriding the address of the puzzle site.
+pub const URL_VAR: &str = "AOC_URL";
+
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+/// File next to the answers file keeping the request limits between runs.
+pub const LIMITS_FILE: &str = ".aoc-limits.toml";
+const USER_AGENT: &str = concat!(
+    "advent-of-code/",
+    env!("CARGO_PKG_VERSION"),
+    " (personal solutions runner)"
+);
+
+/// Whether a wrong numeric answer was too high or too low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// What the site replied to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// The answer was wrong; `wait` is how long the site refuses new answers.
+    Incorrect {
+        hint: Option<Hint>,
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently, nothing was checked.
+    TooSoon {
+        wait: Duration,
+    },
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+}
+
+lazy_static! {
+    static ref LEFT_TO_WAIT: Regex =
+        Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    static ref WAIT_MINUTES: Regex = Regex::new(r"(?i)please wait (one|\d+) minutes?").unwrap();
+}
+
+impl Outcome {
+    /// Reads the outcome out of the HTML page answering a submission.
+    pub fn parse(html: &str) -> Result<Self> {
+        let text = puzzle::text(puzzle::articles(html).next().unwrap_or(html));
+
+        if text.contains("That's the right answer") {
+            Ok(Outcome::Correct)
+        } else if text.contains("That's not the right answer") {
+            let hint = if text.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if text.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            let wait = WAIT_MINUTES.captures(&text).map(|c| {
+                let minutes = c[1].parse().unwrap_or(1);
+                Duration::from_secs(60 * minutes)
+            });
+            Ok(Outcome::Incorrect { hint, wait })
+        } else if text.contains("You gave an answer too recently") {
+            let wait = match LEFT_TO_WAIT.captures(&text) {
+                Some(c) => {
+                    let number = |m: Option<regex::Match>| {
+                        m.map_or(Some(0), |m| m.as_str().parse::<u64>().ok())
+                    };
+                    number(c.get(1))
+                        .and_then(|minutes| minutes.checked_mul(60))
+                        .zip(number(c.get(2)))
+                        .and_then(|(minutes, seconds)| minutes.checked_add(seconds))
+                        .ok_or_else(|| Error::new(format!("invalid wait: {:?}", &c[0])))?
+                }
+                None => 60,
+            };
+            Ok(Outcome::TooSoon {
+                wait: Duration::from_secs(wait),
+            })
+        } else if text.contains("You don't seem to be solving the right level") {
+            Ok(Outcome::WrongLevel)
+        } else {
+            Err(Error::new(format!(
+                "unexpected answer from the site: {:?}",
+                text.trim()
+            )))
+        }
+    }
+}
+
+/// Blocking client for the puzzle site.
+///
+/// Requests are spaced by at least `min_interval`, inputs are only downloaded when
+/// not already saved under `inputs`, and answers are refused locally while the site
+/// asked to wait.
+pub struct Client {
+    url: String,
+    session: String,
+    pub inputs: PathBuf,
+    pub min_interval: Duration,
+    /// File keeping the time of the last request and the end of the wait asked by
+    /// the site, so that they hold across runs; `None` keeps them in memory only.
+    pub limits: Option<PathBuf>,
+    agent: ureq::Agent,
+    last_request: Option<SystemTime>,
+    submit_after: Option<SystemTime>,
+}
+
+impl Client {
+    pub fn new(url: impl Into<String>, session: impl Into<String>) -> Self {
+        Client {
+            url: url.into().trim_end_matches('/').to_owned(),
+            session: session.into(),
+            inputs: input::inputs_dir(),
+            min_interval: Duration::from_secs(5),
+            limits: None,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            last_request: None,
+            submit_after: None,
+        }
+    }
+
+    /// Builds a client from [`SESSION_VAR`] and [`URL_VAR`], the session falling back
+    /// to the crate's `.session` file, keeping its limits in [`LIMITS_FILE`].
+    pub fn from_env() -> Result<Self> {
+        let session = match env::var(SESSION_VAR) {
+            Ok(session) if !session.is_empty() => session,
+            _ => {
+                let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".session");
+                fs::read_to_string(&path).map_err(|_| {
+                    Error::new(format!(
+                        "no session token, set {} or save it in {}",
+                        SESSION_VAR,
+                        path.display()
+                    ))
+                })?
+            }
+        };
+        let url = env::var(URL_VAR)
+            .ok()
+            .filter(|url| !url.is_empty())
+            .unwrap_or_else(|| DEFAULT_URL.to_owned());
+        let mut client = Client::new(url, session.trim());
+        client.limits = Some(Answers::path().with_file_name(LIMITS_FILE));
+        Ok(client)
+    }
+
+    /// Returns the input of a puzzle, downloading and saving it unless already saved.
+    pub fn input(&mut self, year: u16, day: u8) -> Result<String> {
+        let path = input::path_in(&self.inputs, year, day);
+        match fs::read_to_string(&path) {
+            Ok(input) if !input.is_empty() => return Ok(input),
+            _ => {}
+        }
+
+        let request = self.request("GET", &format!("/{}/day/{}/input", year, day))?;
+        let input = read(request.call())?;
+        save(&path, &input)?;
+        Ok(input)
+    }
+
+    /// Downloads the description of a puzzle and saves it next to its input, as
+    /// `<year>/<day>.html`.
+    ///
+    /// It is always downloaded, as the second part only shows once the first is solved.
+    pub fn description(&mut self, year: u16, day: u8) -> Result<String> {
+        let request = self.request("GET", &format!("/{}/day/{}", year, day))?;
+        let html = read(request.call())?;
+        let path = self
+            .inputs
+            .join(year.to_string())
+            .join(format!("{:02}.html", day));
+        save(&path, &html)?;
+        Ok(html)
+    }
+
+    /// Submits the answer of a part.
+    ///
+    /// Fails without contacting the site while a previous reply asked to wait.
+    pub fn submit(&mut self, year: u16, day: u8, part: u8, answer: &Answer) -> Result<Outcome> {
+        self.load_limits()?;
+        if let Some(left) = self
+            .submit_after
+            .and_then(|after| after.duration_since(SystemTime::now()).ok())
+        {
+            return Err(Error::new(format!(
+                "the site asked to wait {}s more before submitting another answer",
+                left.as_secs() + 1
+            )));
+        }
+
+        let request = self.request("POST", &format!("/{}/day/{}/answer", year, day))?;
+        let page = read(request.send_form(&[
+            ("level", &part.to_string()),
+            ("answer", &answer.to_string()),
+        ]))?;
+        let outcome = Outcome::parse(&page)?;
+        self.submit_after = match outcome {
+            Outcome::TooSoon { wait }
+            | Outcome::Incorrect {
+                wait: Some(wait), ..
+            } => Some(SystemTime::now() + wait),
+            _ => None,
+        };
+        self.save_limits()?;
+        Ok(outcome)
+    }
+
+    /// Prepares a request, first sleeping to keep requests `min_interval` apart.
+    fn request(&mut self, method: &str, path: &str) -> Result<ureq::Request> {
+        self.load_limits()?;
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed().unwrap_or_default();
+            if let Some(left) = self.min_interval.checked_sub(elapsed) {
+                thread::sleep(left);
+            }
+        }
+        self.last_request = Some(SystemTime::now());
+        self.save_limits()?;
+        Ok(self
+            .agent
+            .request(method, &format!("{}{}", self.url, path))
+            .set("Cookie", &format!("session={}", self.session)))
+    }
+
+    /// Reads the limits saved by earlier clients, keeping the later of each time.
+    fn load_limits(&mut self) -> Result<()> {
+        let Some(path) = &self.limits else {
+            return Ok(());
+        };
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(Error::new(format!("cannot read {}: {}", path.display(), e))),
+        };
+        let table = text
+            .parse::<toml::Table>()
+            .map_err(|e| Error::new(format!("invalid {}: {}", path.display(), e.message())))?;
+        let time = |key: &str| match table.get(key) {
+            None => Ok(None),
+            Some(value) => value
+                .as_integer()
+                .and_then(|millis| u64::try_from(millis).ok())
+                .map(|millis| Some(UNIX_EPOCH + Duration::from_millis(millis)))
+                .ok_or_else(|| {
+                    Error::new(format!(
+                        "{} in {} must be a time in milliseconds",
+                        key,
+                        path.display()
+                    ))
+                }),
+        };
+        self.last_request = self.last_request.max(time("last_request")?);
+        self.submit_after = self.submit_after.max(time("submit_after")?);
+        Ok(())
+    }
+
+    fn save_limits(&self) -> Result<()> {
+        let Some(path) = &self.limits else {
+            return Ok(());
+        };
+        let millis = |time: SystemTime| {
+            time.duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis()
+        };
+        let mut text = String::new();
+        if let Some(last) = self.last_request {
+            text += &format!("last_request = {}\n", millis(last));
+        }
+        if let Some(after) = self.submit_after {
+            text += &format!("submit_after = {}\n", millis(after));
+        }
+        save(path, &text)
+    }
+}
+
+fn save(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| Error::new(format!("cannot create {}: {}", parent.display(), e)))?;
+    }
+    fs::write(path, content)
+        .map_err(|e| Error::new(format!("cannot write {}: {}", path.display(), e)))
+}
+
+fn read(response: std::result::Result<ureq::Response, ureq::Error>) -> Result<String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| Error::new(format!("cannot read the response: {}", e))),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            Err(Error::new(format!(
+                "the site replied {}: {}",
+                status,
+                body.trim()
+            )))
+        }
+        Err(e) => Err(Error::new(format!("cannot reach the site: {}", e))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{stub::Stub, *};
+
+    fn client(stub: &Stub, name: &str) -> Client {
+        let mut client = Client::new(stub.url(), stub::SESSION);
+        client.inputs = env::temp_dir().join(format!("aoc-client-test-{}", name));
+        let _ = fs::remove_dir_all(&client.inputs);
+        client.min_interval = Duration::ZERO;
+        client
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's the right answer!  You are <em>one gold star</em> closer."
+            ))
+            .unwrap(),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse(&page("That's not the right answer; your answer is too high.  Please wait one minute before trying again.")).unwrap(),
+            Outcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "That's not the right answer.  please wait 5 minutes before trying again."
+            ))
+            .unwrap(),
+            Outcome::Incorrect {
+                hint: None,
+                wait: Some(Duration::from_secs(300))
+            }
+        );
+        assert_eq!(
+            Outcome::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.")).unwrap(),
+            Outcome::TooSoon {
+                wait: Duration::from_secs(65)
+            }
+        );
+        assert_eq!(
+            Outcome::parse(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+            .unwrap(),
+            Outcome::WrongLevel
+        );
+        assert!(Outcome::parse(&page("Something else")).is_err());
+        let error = Outcome::parse(&page(
+            "You gave an answer too recently.  You have 99999999999999999999s left to wait.",
+        ))
+        .unwrap_err();
+        assert!(error.reason.starts_with("invalid wait"), "{}", error);
+    }
+
+    #[test]
+    fn test_input_is_cached() {
+        let stub = Stub::start();
+        stub.set_input(2024, 1, "3   4\n");
+        let mut client = client(&stub, "cache");
+
+        assert_eq!(client.input(2024, 1).unwrap(), "3   4\n");
+        assert_eq!(client.input(2024, 1).unwrap(), "3   4\n");
+        assert_eq!(stub.requests(), ["GET /2024/day/1/input"]);
+        let saved = fs::read_to_string(client.inputs.join("2024/01.txt")).unwrap();
+        assert_eq!(saved, "3   4\n");
+
+        assert!(client.input(2024, 2).is_err());
+        fs::remove_dir_all(&client.inputs).unwrap();
+    }
+
+    #[test]
+    fn test_description() {
+        let stub = Stub::start();
+        stub.set_description(2024, 1, "<article>Day 1</article>");
+        let mut client = client(&stub, "description");
+
+        assert_eq!(
+            client.description(2024, 1).unwrap(),
+            "<article>Day 1</article>"
+        );
+        let saved = fs::read_to_string(client.inputs.join("2024/01.html")).unwrap();
+        assert_eq!(saved, "<article>Day 1</article>");
+        assert_eq!(stub.requests(), ["GET /2024/day/1"]);
+        fs::remove_dir_all(&client.inputs).unwrap();
+    }
+
+    #[test]
+    fn test_bad_session() {
+        let stub = Stub::start();
+        stub.set_input(2024, 1, "3   4\n");
+        let mut client = client(&stub, "session");
+        client.session = "stale".to_owned();
+        let error = client.input(2024, 1).unwrap_err();
+        assert!(error.reason.contains("400"), "{}", error);
+    }
+
+    #[test]
+    fn test_submit() {
+        let stub = Stub::start();
+        stub.set_answer(2024, 1, 1, "11");
+        let mut client = client(&stub, "submit");
+
+        assert_eq!(
+            client.submit(2024, 1, 1, &Answer::from(12)).unwrap(),
+            Outcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        // refused locally, the site is not contacted
+        assert!(client.submit(2024, 1, 1, &Answer::from(11)).is_err());
+        assert_eq!(stub.requests().len(), 1);
+
+        client.submit_after = None;
+        assert_eq!(
+            client.submit(2024, 1, 1, &Answer::from(11)).unwrap(),
+            Outcome::TooSoon {
+                wait: Duration::from_secs(60)
+            }
+        );
+
+        stub.reset_wait();
+        client.submit_after = None;
+        assert_eq!(
+            client.submit(2024, 1, 1, &Answer::from(10)).unwrap(),
+            Outcome::Incorrect {
+                hint: Some(Hint::TooLow),
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        stub.reset_wait();
+        client.submit_after = None;
+        assert_eq!(
+            client.submit(2024, 1, 1, &Answer::from(11)).unwrap(),
+            Outcome::Correct
+        );
+        assert_eq!(
+            client.submit(2024, 1, 1, &Answer::from(11)).unwrap(),
+            Outcome::WrongLevel
+        );
+    }
+
+    #[test]
+    fn test_limits_across_clients() {
+        let stub = Stub::start();
+        stub.set_answer(2024, 1, 1, "11");
+        let limits = env::temp_dir().join("aoc-client-test-limits.toml");
+        let _ = fs::remove_file(&limits);
+        let mut first = client(&stub, "limits");
+        first.limits = Some(limits.clone());
+        assert!(matches!(
+            first.submit(2024, 1, 1, &Answer::from(12)).unwrap(),
+            Outcome::Incorrect { .. }
+        ));
+
+        // a later run is refused locally, the site is not contacted
+        let mut second = client(&stub, "limits");
+        second.limits = Some(limits.clone());
+        let error = second.submit(2024, 1, 1, &Answer::from(11)).unwrap_err();
+        assert!(error.reason.contains("asked to wait"), "{}", error);
+        assert_eq!(stub.requests().len(), 1);
+
+        let saved = fs::read_to_string(&limits).unwrap();
+        assert!(saved.contains("last_request = ") && saved.contains("submit_after = "));
+        fs::write(&limits, "submit_after = \"soon\"\n").unwrap();
+        assert!(second.submit(2024, 1, 1, &Answer::from(11)).is_err());
+        fs::remove_file(&limits).unwrap();
+    }
+}
@@ -0,0 +1,270 @@
+//! Local stand-in for the puzzle site, serving the input and answer endpoints over
+//! plain HTTP so that the client can be exercised offline.
+
+use std::{
+    collections::{HashMap, HashSet},
+    io::{BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+/// Session token accepted by the stub.
+pub const SESSION: &str = "stub-session";
+/// How long the stub refuses answers after a wrong one, as the site does.
+pub const COOLDOWN: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+struct State {
+    inputs: HashMap<(u16, u8), String>,
+    descriptions: HashMap<(u16, u8), String>,
+    answers: HashMap<(u16, u8, u8), String>,
+    solved: HashSet<(u16, u8, u8)>,
+    wait_until: Option<Instant>,
+    requests: Vec<String>,
+}
+
+/// Stub server listening on a local port until dropped.
+pub struct Stub {
+    address: SocketAddr,
+    state: Arc<Mutex<State>>,
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Stub {
+    pub fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("cannot bind the stub server");
+        let address = listener.local_addr().unwrap();
+        let state = Arc::new(Mutex::new(State::default()));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let handle = {
+            let (state, stop) = (state.clone(), stop.clone());
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if stop.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        // a broken connection only fails the request it carried
+                        let _ = serve(stream, &state);
+                    }
+                }
+            })
+        };
+        Stub {
+            address,
+            state,
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    pub fn set_input(&self, year: u16, day: u8, input: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.inputs.insert((year, day), input.to_owned());
+    }
+
+    pub fn set_description(&self, year: u16, day: u8, html: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.descriptions.insert((year, day), html.to_owned());
+    }
+
+    pub fn set_answer(&self, year: u16, day: u8, part: u8, answer: &str) {
+        let mut state = self.state.lock().unwrap();
+        state.answers.insert((year, day, part), answer.to_owned());
+    }
+
+    /// Lifts the cooldown following a wrong answer.
+    pub fn reset_wait(&self) {
+        self.state.lock().unwrap().wait_until = None;
+    }
+
+    /// Returns the requests received so far, as `METHOD /path`.
+    pub fn requests(&self) -> Vec<String> {
+        self.state.lock().unwrap().requests.clone()
+    }
+}
+
+impl Drop for Stub {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        // wakes the listener up so that it sees the stop flag
+        let _ = TcpStream::connect(self.address);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn serve(stream: TcpStream, state: &Mutex<State>) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next().unwrap_or(""), parts.next().unwrap_or(""));
+
+    let (mut length, mut session) = (0, None);
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            continue;
+        };
+        match name.to_ascii_lowercase().as_str() {
+            "content-length" => length = value.trim().parse().unwrap_or(0),
+            "cookie" => {
+                session = value
+                    .split(';')
+                    .find_map(|c| c.trim().strip_prefix("session="))
+                    .map(str::to_owned)
+            }
+            _ => {}
+        }
+    }
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    let mut state = state.lock().unwrap();
+    state.requests.push(format!("{} {}", method, path));
+    let (status, body) = if session.as_deref() != Some(SESSION) {
+        (
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.".to_owned(),
+        )
+    } else {
+        respond(&mut state, method, path, &String::from_utf8_lossy(&body))
+    };
+
+    let reason = match status {
+        200 => "OK",
+        400 => "Bad Request",
+        _ => "Not Found",
+    };
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+fn respond(state: &mut State, method: &str, path: &str, form: &str) -> (u16, String) {
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    let (year, day, endpoint) = match segments[..] {
+        [year, "day", day] => (year, day, ""),
+        [year, "day", day, endpoint] => (year, day, endpoint),
+        _ => return (404, "404 Not Found".to_owned()),
+    };
+    let (year, day) = match (year.parse(), day.parse()) {
+        (Ok(year), Ok(day)) => (year, day),
+        _ => return (404, "404 Not Found".to_owned()),
+    };
+
+    match (method, endpoint) {
+        ("GET", "") => match state.descriptions.get(&(year, day)) {
+            Some(html) => (200, html.clone()),
+            None => (404, "404 Not Found".to_owned()),
+        },
+        ("GET", "input") => match state.inputs.get(&(year, day)) {
+            Some(input) => (200, input.clone()),
+            None => (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!".to_owned(),
+            ),
+        },
+        ("POST", "answer") => {
+            let field = |name: &str| {
+                form.split('&')
+                    .find_map(|f| f.strip_prefix(name)?.strip_prefix('='))
+                    .map(decode)
+                    .unwrap_or_default()
+            };
+            let part = field("level").parse().unwrap_or(0);
+            (
+                200,
+                page(&submit(state, (year, day, part), &field("answer"))),
+            )
+        }
+        _ => (404, "404 Not Found".to_owned()),
+    }
+}
+
+fn submit(state: &mut State, key: (u16, u8, u8), answer: &str) -> String {
+    let now = Instant::now();
+    if let Some(left) = state
+        .wait_until
+        .and_then(|until| until.checked_duration_since(now))
+    {
+        let seconds = left.as_secs() + u64::from(left.subsec_nanos() > 0);
+        let left = match seconds / 60 {
+            0 => format!("{}s", seconds),
+            minutes => format!("{}m {}s", minutes, seconds % 60),
+        };
+        return format!("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {} left to wait.", left);
+    }
+    let expected = match state.answers.get(&key) {
+        Some(expected) if !state.solved.contains(&key) => expected.clone(),
+        _ => {
+            return "You don't seem to be solving the right level.  Did you already complete it?"
+                .to_owned()
+        }
+    };
+
+    if answer == expected {
+        state.solved.insert(key);
+        return "That's the right answer!  You are <em>one gold star</em> closer.".to_owned();
+    }
+    state.wait_until = Some(now + COOLDOWN);
+    let hint = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+        (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+        (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+        _ => "",
+    };
+    format!(
+        "That's not the right answer{}.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.",
+        hint
+    )
+}
+
+fn page(message: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>\n",
+        message
+    )
+}
+
+/// Decodes an `application/x-www-form-urlencoded` value.
+fn decode(value: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut iter = value.bytes();
+    while let Some(b) = iter.next() {
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = [iter.next().unwrap_or(b'0'), iter.next().unwrap_or(b'0')];
+                let hex = std::str::from_utf8(&hex).unwrap_or("00");
+                bytes.push(u8::from_str_radix(hex, 16).unwrap_or(b'?'));
+            }
+            b => bytes.push(b),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
@@ -0,0 +1,136 @@
+use std::any::Any;
+
+use crate::{answer::Answer, error::Result, solution::AnySolution};
+
+// A new day takes a line here and one in `register!` below, `aoc new` adds both.
+// The modules stay out of the macro because rustfmt skips those declared inside.
+pub mod y2023d01;
+pub mod y2023d02;
+pub mod y2023d03;
+pub mod y2023d04;
+pub mod y2023d05;
+pub mod y2023d06;
+pub mod y2024d01;
+pub mod y2024d02;
+pub mod y2024d03;
+pub mod y2024d04;
+pub mod y2024d05;
+pub mod y2024d06;
+
+/// A solved puzzle of the registry.
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub solution: &'static dyn AnySolution,
+}
+
+/// Lists the `Day` solution of each day module in [`REGISTRY`].
+macro_rules! register {
+    ($($module:ident => ($year:literal, $day:literal)),* $(,)?) => {
+        /// Every solved puzzle, ordered by year and day.
+        pub static REGISTRY: &[Entry] = &[$(
+            Entry {
+                year: $year,
+                day: $day,
+                solution: &$module::Day,
+            },
+        )*];
+    };
+}
+
+register! {
+    y2023d01 => (2023, 1),
+    y2023d02 => (2023, 2),
+    y2023d03 => (2023, 3),
+    y2023d04 => (2023, 4),
+    y2023d05 => (2023, 5),
+    y2023d06 => (2023, 6),
+    y2024d01 => (2024, 1),
+    y2024d02 => (2024, 2),
+    y2024d03 => (2024, 3),
+    y2024d04 => (2024, 4),
+    y2024d05 => (2024, 5),
+    y2024d06 => (2024, 6),
+}
+
+impl Entry {
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        self.solution
+            .parse_any(input)
+            .map_err(|e| e.in_puzzle(self.year, self.day))
+    }
+
+    pub fn part1(&self, input: &dyn Any) -> Result<Answer> {
+        self.solution
+            .part1_any(input)
+            .map_err(|e| e.in_puzzle(self.year, self.day))
+    }
+
+    pub fn part2(&self, input: &dyn Any) -> Result<Answer> {
+        self.solution
+            .part2_any(input)
+            .map_err(|e| e.in_puzzle(self.year, self.day))
+    }
+}
+
+/// Returns the solution registered for the given puzzle, if any.
+pub fn get(year: u16, day: u8) -> Option<&'static Entry> {
+    REGISTRY.iter().find(|e| e.year == year && e.day == day)
+}
+
+/// Returns the solutions registered for the given year.
+pub fn of_year(year: u16) -> impl Iterator<Item = &'static Entry> {
+    REGISTRY.iter().filter(move |e| e.year == year)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_sorted_and_unique() {
+        assert!(REGISTRY
+            .windows(2)
+            .all(|w| (w[0].year, w[0].day) < (w[1].year, w[1].day)));
+    }
+
+    #[test]
+    fn test_get() {
+        assert!(get(2024, 6).is_some());
+        assert!(get(2024, 26).is_none());
+    }
+
+    #[test]
+    fn test_of_year() {
+        assert_eq!(of_year(2023).count(), 6);
+        assert!(of_year(2023).all(|e| e.year == 2023));
+    }
+
+    #[test]
+    fn test_run_erased() {
+        let entry = get(2024, 1).unwrap();
+        let input = entry
+            .parse("3   4\n4   3\n2   5\n1   3\n3   9\n3   3")
+            .unwrap();
+        assert_eq!(entry.part1(input.as_ref()).unwrap(), "11");
+        assert_eq!(entry.part2(input.as_ref()).unwrap(), "31");
+    }
+
+    #[test]
+    fn test_error_carries_puzzle() {
+        let entry = get(2024, 1).unwrap();
+        let error = entry.parse("3   4\n4   x").unwrap_err();
+        assert_eq!(error.puzzle, Some((2024, 1)));
+        assert_eq!((error.line, error.column), (Some(2), Some(5)));
+    }
+
+    #[test]
+    fn test_malformed_lines() {
+        let error = get(2024, 1).unwrap().parse("3   4\n42").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(3)));
+        let error = get(2024, 1).unwrap().parse("3   4 5").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(1), Some(7)));
+        let error = get(2023, 5).unwrap().parse("seeds: 79 14 55").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(1), Some(14)));
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    solution::Solution,
+};
+
+const LITERALS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        sum_lines(input, get_calibration_value).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        sum_lines(input, |line| get_real_calibration_value(line, &LITERALS)).map(Answer::from)
+    }
+}
+
+fn sum_lines(input: &[String], value: impl Fn(&str) -> Option<i32>) -> Result<i32> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            value(line).ok_or_else(|| Error::new("no digit in the line").on_line(i + 1))
+        })
+        .sum()
+}
+
+fn get_calibration_value(input: &str) -> Option<i32> {
+    let mut digits = input.chars().filter_map(|c| c.to_digit(10));
+    let first = digits.next()?;
+    let last = digits.next_back().unwrap_or(first);
+    Some((first * 10 + last) as i32)
+}
+
+fn get_real_calibration_value(input: &str, literals: &[&str]) -> Option<i32> {
+    let mut temp = input.to_owned();
+
+    for (i, name) in literals.iter().enumerate() {
+        temp = temp.replace(name, format!("{}{}{}", name, i, name).as_str());
+    }
+
+    get_calibration_value(temp.as_str())
+}
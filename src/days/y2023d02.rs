@@ -0,0 +1,90 @@
+use std::str::FromStr;
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    solution::Solution,
+    utils::conversions::{parse_at, Parser},
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse_lines()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input
+            .iter()
+            .filter(|x| x.is_valid(&12, &13, &14))
+            .map(|x| x.id)
+            .sum::<i32>()
+            .into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.iter().map(|x| x.power()).sum::<i32>().into())
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    id: i32,
+    sets: Vec<(i32, i32, i32)>,
+}
+
+impl FromStr for Game {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let (game, sets) = input
+            .split_once(": ")
+            .ok_or_else(|| Error::new("expected 'Game <id>: <sets>'").at(input, input))?;
+
+        let (_, id) = game
+            .split_once(' ')
+            .ok_or_else(|| Error::new("expected 'Game <id>'").at(input, game))?;
+        let id = parse_at(input, id)?;
+
+        let mut temp = Vec::new();
+        for set in sets.split("; ") {
+            let (mut r, mut g, mut b) = (0, 0, 0);
+            for pair in set.split(", ") {
+                let (num, color) = pair
+                    .split_once(' ')
+                    .ok_or_else(|| Error::new("expected '<count> <color>'").at(input, pair))?;
+                let num = parse_at(input, num)?;
+                match color {
+                    "red" => r = num,
+                    "green" => g = num,
+                    "blue" => b = num,
+                    _ => {
+                        return Err(
+                            Error::new(format!("invalid color {:?}", color)).at(input, color)
+                        )
+                    }
+                }
+            }
+            temp.push((r, g, b));
+        }
+        Ok(Game { id, sets: temp })
+    }
+}
+
+impl Game {
+    fn is_valid(&self, max_red: &i32, max_green: &i32, max_blue: &i32) -> bool {
+        self.sets
+            .iter()
+            .all(|(r, g, b)| r <= max_red && g <= max_green && b <= max_blue)
+    }
+
+    fn power(&self) -> i32 {
+        let r = self.sets.iter().map(|s| s.0).max().unwrap_or(0);
+        let g = self.sets.iter().map(|s| s.1).max().unwrap_or(0);
+        let b = self.sets.iter().map(|s| s.2).max().unwrap_or(0);
+        r * g * b
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    answer::Answer,
+    error::Result,
+    solution::Solution,
+    utils::{grid::*, point::Vec2},
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(get_engine_parts(input)
+            .iter()
+            .map(|p| p.value)
+            .sum::<i32>()
+            .into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(get_gear_ratios(&get_engine_parts(input))
+            .iter()
+            .sum::<i32>()
+            .into())
+    }
+}
+
+struct EnginePart {
+    value: i32,
+    _symbol: char,
+    symbol_pos: Vec2,
+}
+
+impl EnginePart {
+    fn new(value: i32, symbol: char, symbol_pos: Vec2) -> Self {
+        Self {
+            value,
+            _symbol: symbol,
+            symbol_pos,
+        }
+    }
+}
+
+fn get_engine_parts(input: &Grid<char>) -> Vec<EnginePart> {
+    let mut output = Vec::new();
+    let mut value = 0;
+    let mut has_symbol = false;
+    let mut symbol_pos = Vec2::default();
+    let mut symbol = '.';
+    for pos in input.iter_positions() {
+        match input[pos].to_digit(10) {
+            Some(digit) => {
+                value = value * 10 + digit as i32;
+                if let Some((p, &x)) = input
+                    .neighbor_cells(pos, Neighborhood::Moore)
+                    .find(|(_, &x)| x != '.' && !x.is_ascii_digit())
+                {
+                    has_symbol = true;
+                    symbol = x;
+                    symbol_pos = p;
+                }
+            }
+            None => {
+                if value != 0 && has_symbol {
+                    output.push(EnginePart::new(value, symbol, symbol_pos));
+                }
+                value = 0;
+                has_symbol = false;
+                symbol = '.';
+                symbol_pos = Vec2::default();
+            }
+        }
+    }
+    output
+}
+
+fn get_gear_ratios(input: &[EnginePart]) -> Vec<i32> {
+    let mut output = Vec::new();
+    for i in 0..input.len() {
+        for j in i + 1..input.len() {
+            if input[i].symbol_pos == input[j].symbol_pos {
+                output.push(input[i].value * input[j].value)
+            }
+        }
+    }
+    output
+}
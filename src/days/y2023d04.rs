@@ -0,0 +1,115 @@
+use std::str::FromStr;
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    solution::Solution,
+    utils::conversions::{parse_at, Parser},
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Card>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse_lines()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.iter().map(|c| c.get_points()).sum::<u32>().into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let mut cards = input.clone();
+        for i in 0..cards.len() {
+            let n = cards[i].quantity;
+            let matches = cards[i].get_n_matches() as usize;
+            if i + matches >= cards.len() {
+                return Err(Error::new(format!(
+                    "card {} wins copies of cards past the end of the table",
+                    cards[i]._id
+                ))
+                .on_line(i + 1));
+            }
+            for j in 0..matches {
+                cards[i + 1 + j].increase_quantity(n);
+            }
+        }
+        Ok(cards.iter().map(|c| c.quantity).sum::<u32>().into())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Card {
+    winning_numbers: Vec<i32>,
+    my_numbers: Vec<i32>,
+    _id: i32,
+    quantity: u32,
+}
+
+impl Card {
+    fn new(id: i32, winning_numbers: Vec<i32>, my_numbers: Vec<i32>) -> Self {
+        Card {
+            _id: id,
+            winning_numbers,
+            my_numbers,
+            quantity: 1,
+        }
+    }
+
+    fn get_points(&self) -> u32 {
+        let mut points = -1;
+        for number in self.my_numbers.iter() {
+            if self.winning_numbers.contains(number) {
+                points += 1;
+            }
+        }
+        if points == -1 {
+            return 0;
+        }
+        2u32.pow(points as u32)
+    }
+
+    fn get_n_matches(&self) -> u32 {
+        self.my_numbers
+            .iter()
+            .filter(|x| self.winning_numbers.contains(x))
+            .count() as u32
+    }
+
+    fn increase_quantity(&mut self, n: u32) {
+        self.quantity += n;
+    }
+}
+
+impl FromStr for Card {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        let (card, numbers) = input
+            .split_once(": ")
+            .ok_or_else(|| Error::new("expected 'Card <id>: <numbers>'").at(input, input))?;
+
+        let id = card
+            .split(' ')
+            .next_back()
+            .ok_or_else(|| Error::new("expected 'Card <id>'").at(input, card))?;
+        let id = parse_at(input, id)?;
+
+        let (raw_winning_numbers, raw_my_numbers) = numbers
+            .split_once(" | ")
+            .ok_or_else(|| Error::new("expected '<winning> | <mine>'").at(input, numbers))?;
+
+        let winning_numbers = raw_winning_numbers
+            .split_whitespace()
+            .map(|n| parse_at(input, n))
+            .collect::<Result<_>>()?;
+        let my_numbers = raw_my_numbers
+            .split_whitespace()
+            .map(|n| parse_at(input, n))
+            .collect::<Result<_>>()?;
+
+        Ok(Card::new(id, winning_numbers, my_numbers))
+    }
+}
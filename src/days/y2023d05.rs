@@ -0,0 +1,208 @@
+use std::{collections::HashSet, str::FromStr};
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    solution::Solution,
+    utils::conversions::parse_at,
+};
+use regex::Regex;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Almanac;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        search_min(input.seeds.clone(), |s| input.map_seed(s)).map(Answer::from)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        search_min_2(input).map(Answer::from)
+    }
+}
+
+fn search_min<F>(seeds: Vec<u64>, mapper: F) -> Result<u64>
+where
+    F: Fn(u64) -> u64,
+{
+    seeds
+        .iter()
+        .map(|&s| mapper(s))
+        .min()
+        .ok_or_else(|| Error::new("the almanac lists no seeds"))
+}
+
+fn search_min_2(input: &Almanac) -> Result<u64> {
+    let reversed_categories = input.reverse_categories();
+    let mut points = HashSet::new();
+
+    for i in 0..reversed_categories.len() {
+        let cat = &reversed_categories[i];
+        cat.maps.iter().for_each(|m| {
+            points.insert(m.dest);
+            points.insert(m.dest + m.length - 1);
+        });
+
+        if i < reversed_categories.len() - 1 {
+            let mut new_points = HashSet::new();
+            points.iter().for_each(|p| {
+                let new_p = reversed_categories[i + 1]
+                    .maps
+                    .iter()
+                    .find(|m| m.contains(*p))
+                    .map(|m| m.map_item(*p))
+                    .unwrap_or(*p);
+                new_points.insert(new_p);
+            });
+            points.extend(new_points);
+        }
+    }
+
+    let mut test_points = input
+        .seed_ranges
+        .iter()
+        .flat_map(|r| vec![r.start, r.end])
+        .collect::<HashSet<_>>();
+    test_points.extend(points.iter().filter(|p| {
+        input
+            .seed_ranges
+            .iter()
+            .any(|r| r.start < **p && **p < r.end)
+    }));
+
+    let mapper = |s| input.map_seed(s);
+    search_min(test_points.into_iter().collect(), mapper)
+}
+
+#[derive(Default, Debug)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    seed_ranges: Vec<SeedRange>,
+    categories: Vec<Category>,
+}
+
+#[derive(Debug, Clone)]
+struct SeedRange {
+    start: u64,
+    end: u64,
+    _length: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Category {
+    maps: Vec<Map>,
+}
+
+#[derive(Debug, Clone)]
+struct Map {
+    dest: u64,
+    source: u64,
+    length: u64,
+}
+
+impl Almanac {
+    fn map_seed(&self, seed: u64) -> u64 {
+        self.categories.iter().fold(seed, |acc, c| {
+            c.maps
+                .iter()
+                .find(|m| m.contains(acc))
+                .map(|m| m.map_item(acc))
+                .unwrap_or(acc)
+        })
+    }
+
+    fn reverse_categories(&self) -> Vec<Category> {
+        self.categories
+            .iter()
+            .cloned()
+            .rev()
+            .map(|c| Category {
+                maps: c
+                    .maps
+                    .into_iter()
+                    .map(|m| Map::new(m.source, m.dest, m.length))
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+impl Map {
+    fn new(dest: u64, source: u64, length: u64) -> Self {
+        assert!(length > 0);
+        Map {
+            dest,
+            source,
+            length,
+        }
+    }
+
+    fn map_item(&self, item: u64) -> u64 {
+        assert!(self.contains(item));
+        let offset = item - self.source;
+        self.dest + offset
+    }
+
+    fn contains(&self, item: u64) -> bool {
+        item >= self.source && item < self.source + self.length
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut pieces = s.split("\n\n");
+        let raw_seeds = pieces.next().unwrap_or_default();
+        if !raw_seeds.starts_with("seeds:") {
+            return Err(Error::new("expected the 'seeds:' list").at(s, raw_seeds));
+        }
+        let re = Regex::new(r"\d+").unwrap();
+        let seeds: Vec<u64> = re
+            .find_iter(raw_seeds)
+            .map(|m| parse_at(s, m.as_str()))
+            .collect::<Result<_>>()?;
+        if seeds.len() % 2 == 1 {
+            let last = re.find_iter(raw_seeds).last().unwrap().as_str();
+            return Err(Error::new("seed without a range length").at(s, last));
+        }
+        let mut seed_ranges = Vec::new();
+        for pair in seeds.chunks_exact(2) {
+            let (start, length) = (pair[0], pair[1]);
+            if length == 0 {
+                return Err(Error::new(format!("empty seed range at {}", start)).at(s, raw_seeds));
+            }
+            seed_ranges.push(SeedRange {
+                start,
+                end: start + length - 1,
+                _length: length,
+            });
+        }
+        let re = Regex::new(r"(\d+) (\d+) (\d+)").unwrap();
+        let categories = pieces
+            .map(|p| {
+                let maps = re
+                    .captures_iter(p)
+                    .map(|c| {
+                        let length = parse_at(s, &c[3])?;
+                        if length == 0 {
+                            return Err(Error::new("empty map range").at(s, &c[3]));
+                        }
+                        Ok(Map::new(parse_at(s, &c[1])?, parse_at(s, &c[2])?, length))
+                    })
+                    .collect::<Result<_>>()?;
+                Ok(Category { maps })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Almanac {
+            seeds,
+            seed_ranges,
+            categories,
+        })
+    }
+}
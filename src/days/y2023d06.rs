@@ -0,0 +1,90 @@
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    solution::Solution,
+    utils::conversions::parse_at,
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Race>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let (raw_times, raw_distances) = input
+            .split_once('\n')
+            .ok_or_else(|| Error::new("expected a 'Time:' and a 'Distance:' line"))?;
+        let times = parse_numbers(input, raw_times)?;
+        let distances = parse_numbers(input, raw_distances)?;
+        if times.len() != distances.len() {
+            return Err(Error::new(format!(
+                "{} times but {} distances",
+                times.len(),
+                distances.len()
+            ))
+            .at(input, raw_distances));
+        }
+        Ok(times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, record_distance)| Race::new(time, record_distance))
+            .collect())
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input
+            .iter()
+            .map(|r| r.win_situations())
+            .product::<u64>()
+            .into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let time = join_digits(input.iter().map(|r| r.time));
+        let record_distance = join_digits(input.iter().map(|r| r.record_distance));
+        Ok(Race::new(time, record_distance).win_situations().into())
+    }
+}
+
+fn parse_numbers<'a>(input: &'a str, line: &'a str) -> Result<Vec<u64>> {
+    let (_, numbers) = line
+        .split_once(':')
+        .ok_or_else(|| Error::new("expected '<label>: <numbers>'").at(input, line))?;
+    numbers
+        .split_whitespace()
+        .map(|n| parse_at(input, n))
+        .collect()
+}
+
+/// Concatenates the decimal digits of the numbers, ignoring the kerning.
+fn join_digits(numbers: impl Iterator<Item = u64>) -> u64 {
+    numbers.fold(0, |acc, n| acc * 10u64.pow(n.to_string().len() as u32) + n)
+}
+
+pub struct Race {
+    time: u64,
+    record_distance: u64,
+}
+
+impl Race {
+    fn new(time: u64, record_distance: u64) -> Self {
+        Self {
+            time,
+            record_distance,
+        }
+    }
+
+    fn run(&self, button_time: u64) -> u64 {
+        (self.time - button_time) * button_time
+    }
+
+    fn win_situations(&self) -> u64 {
+        let mut win_situations = 0;
+        for button_time in 0..self.time {
+            if self.run(button_time) > self.record_distance {
+                win_situations += 1;
+            }
+        }
+        win_situations
+    }
+}
@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    solution::Solution,
+    utils::conversions::parse_at,
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<u32>, Vec<u32>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+        for line in input.lines().filter(|l| !l.trim().is_empty()) {
+            let mut numbers = line.split_whitespace();
+            match (numbers.next(), numbers.next(), numbers.next()) {
+                (Some(l), Some(r), None) => {
+                    left.push(parse_at(input, l)?);
+                    right.push(parse_at(input, r)?);
+                }
+                (_, _, Some(extra)) => {
+                    return Err(Error::new("expected two numbers, found more").at(input, extra))
+                }
+                _ => return Err(Error::new("expected two numbers").at(input, &line[line.len()..])),
+            }
+        }
+        left.sort_unstable();
+        right.sort_unstable();
+        Ok((left, right))
+    }
+
+    fn part1(&self, (left, right): &Self::Input) -> Result<Answer> {
+        Ok(left
+            .iter()
+            .zip(right.iter())
+            .map(|(l, r)| l.abs_diff(*r))
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(&self, (left, right): &Self::Input) -> Result<Answer> {
+        let mut occurrences = HashMap::new();
+        for &number in right {
+            *occurrences.entry(number).or_insert(0) += 1;
+        }
+
+        Ok(left
+            .iter()
+            .map(|x| *x * occurrences.get(x).unwrap_or(&0))
+            .sum::<u32>()
+            .into())
+    }
+}
@@ -0,0 +1,83 @@
+use std::str::FromStr;
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    solution::Solution,
+    utils::conversions::{parse_at, Parser},
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Report>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse_lines()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.iter().filter(|r| r.safe).count().into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.iter().filter(|r| r.weakly_safe).count().into())
+    }
+}
+
+#[derive(Debug)]
+pub struct Report {
+    _levels: Vec<i32>,
+    safe: bool,
+    weakly_safe: bool,
+}
+
+impl Report {
+    fn is_safe(levels: &[i32]) -> bool {
+        let mut is_increasing = true;
+        let mut is_decreasing = true;
+
+        let is_adjacent_diff_valid = levels.windows(2).all(|w| {
+            if w[0] < w[1] {
+                is_decreasing = false;
+            } else if w[0] > w[1] {
+                is_increasing = false;
+            }
+            (w[0] - w[1]).abs() >= 1 && (w[0] - w[1]).abs() <= 3
+        });
+
+        (is_increasing || is_decreasing) && is_adjacent_diff_valid
+    }
+
+    fn new(levels: Vec<i32>) -> Self {
+        let is_safe = Self::is_safe(&levels);
+
+        let is_weakly_safe = (0..levels.len()).any(|i| {
+            let mut temp = levels.clone();
+            temp.remove(i);
+            Self::is_safe(&temp)
+        });
+
+        Self {
+            _levels: levels,
+            safe: is_safe,
+            weakly_safe: is_weakly_safe,
+        }
+    }
+}
+
+impl FromStr for Report {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let levels = s
+            .split_whitespace()
+            .map(|x| parse_at(s, x))
+            .collect::<Result<Vec<_>>>()?;
+        if levels.is_empty() {
+            Err(Error::new("empty report"))
+        } else {
+            Ok(Self::new(levels))
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{answer::Answer, error::Result, solution::Solution, utils::conversions::parse_at};
+use regex::Regex;
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Mul>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let mul_regex = Regex::new(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)").unwrap();
+        let mut muls = Vec::new();
+        let mut enable = true;
+        for cap in mul_regex.captures_iter(input) {
+            match &cap[0] {
+                "do()" => enable = true,
+                "don't()" => enable = false,
+                _ => muls.push(Mul {
+                    x: parse_at(input, &cap[1])?,
+                    y: parse_at(input, &cap[2])?,
+                    enabled: enable,
+                }),
+            }
+        }
+        Ok(muls)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.iter().map(|mul| mul.x * mul.y).sum::<u32>().into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input
+            .iter()
+            .filter(|mul| mul.enabled)
+            .map(|mul| mul.x * mul.y)
+            .sum::<u32>()
+            .into())
+    }
+}
+
+pub struct Mul {
+    x: u32,
+    y: u32,
+    enabled: bool,
+}
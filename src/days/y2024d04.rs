@@ -0,0 +1,71 @@
+use std::str::FromStr;
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    solution::Solution,
+    utils::{
+        grid::{Grid, Symmetry},
+        point::{Vec2, DIRECTIONS8},
+    },
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = WordSearch;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.search_word("XMAS").len().into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        let x_mas = Grid::parse_with("M.M\n.A.\nS.S", |c, _| Ok((c != '.').then_some(c)))?;
+        Ok(input
+            .haystack
+            .find_pattern_with(&x_mas, Symmetry::Rotations)
+            .len()
+            .into())
+    }
+}
+
+pub struct WordSearch {
+    haystack: Grid<char>,
+}
+
+/// Where a word starts and the direction it is written in.
+type Word = (Vec2, Vec2);
+
+impl WordSearch {
+    fn search_word(&self, word: &str) -> Vec<Word> {
+        let length = word.chars().count();
+        self.haystack
+            .iter_positions()
+            .flat_map(|start| DIRECTIONS8.iter().map(move |&direction| (start, direction)))
+            .filter(|&(start, direction)| {
+                self.haystack
+                    .ray(start, direction)
+                    .values()
+                    .take(length)
+                    .copied()
+                    .eq(word.chars())
+            })
+            .collect()
+    }
+}
+
+impl FromStr for WordSearch {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let haystack: Grid<char> = s.parse()?;
+        if haystack.width < 2 || haystack.height < 2 {
+            return Err(Error::new("the word search must be at least 2x2"));
+        }
+        Ok(WordSearch { haystack })
+    }
+}
@@ -0,0 +1,125 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    solution::Solution,
+    utils::conversions::parse_at,
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Day5;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, day5: &Self::Input) -> Result<Answer> {
+        Ok(day5
+            .updates
+            .iter()
+            .filter(|u| u.is_correct(&day5.update_rules))
+            .map(|u| u.mean_value)
+            .sum::<u32>()
+            .into())
+    }
+
+    fn part2(&self, day5: &Self::Input) -> Result<Answer> {
+        let update_rules = day5.update_rules.iter().copied().collect();
+        Ok(day5
+            .updates
+            .iter()
+            .filter(|u| !u.is_correct(&day5.update_rules))
+            .map(|u| u.clone().into_correct(&update_rules).mean_value)
+            .sum::<u32>()
+            .into())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Update {
+    pages: Vec<u32>,
+    mean_value: u32,
+    indexes: HashMap<u32, usize>,
+}
+
+pub struct Day5 {
+    update_rules: Vec<(u32, u32)>,
+    updates: Vec<Update>,
+}
+
+impl Update {
+    pub fn new(pages: Vec<u32>) -> Self {
+        let mut indexes = HashMap::new();
+        for (i, e) in pages.iter().enumerate() {
+            indexes.insert(*e, i);
+        }
+        let mean_value = pages[pages.len() / 2];
+        Update {
+            pages,
+            indexes,
+            mean_value,
+        }
+    }
+
+    pub fn is_correct(&self, update_rules: &[(u32, u32)]) -> bool {
+        update_rules
+            .iter()
+            .all(|(a, b)| match (self.indexes.get(a), self.indexes.get(b)) {
+                (Some(&a_idx), Some(&b_idx)) => a_idx < b_idx,
+                _ => true,
+            })
+    }
+
+    pub fn into_correct(self, update_rules: &HashSet<(u32, u32)>) -> Self {
+        let Self { mut pages, .. } = self;
+        pages.sort_by(|a, b| {
+            if update_rules.contains(&(*a, *b)) {
+                Ordering::Less
+            } else if update_rules.contains(&(*b, *a)) {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        });
+        Self::new(pages)
+    }
+}
+
+impl FromStr for Day5 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (rules, updates) = s.split_once("\n\n").ok_or_else(|| {
+            Error::new("expected the rules and the updates split by a blank line")
+        })?;
+        let update_rules = rules
+            .lines()
+            .map(|l| {
+                let (a, b) = l
+                    .split_once('|')
+                    .ok_or_else(|| Error::new("expected '<page>|<page>'").at(s, l))?;
+                Ok((parse_at(s, a)?, parse_at(s, b)?))
+            })
+            .collect::<Result<_>>()?;
+        let updates = updates
+            .lines()
+            .map(|l| {
+                l.split(',')
+                    .map(|p| parse_at(s, p))
+                    .collect::<Result<_>>()
+                    .map(Update::new)
+            })
+            .collect::<Result<_>>()?;
+        Ok(Day5 {
+            update_rules,
+            updates,
+        })
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    solution::Solution,
+    tiles,
+    utils::{grid::*, point::*},
+};
+
+tiles! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Cell {
+        Empty = '.',
+        Obstacle = '#',
+        Guard = '^',
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<Cell>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Answer> {
+        let start = find_start(map)?;
+        Ok(count_unique_positions(map, start).len().into())
+    }
+
+    fn part2(&self, map: &Self::Input) -> Result<Answer> {
+        let start = find_start(map)?;
+        // si potrebbe evitare la clone della map perchè basta resettare il carattere modificato
+        // una volta checkato il loop
+        Ok(count_unique_positions(map, start)
+            .into_iter()
+            .map(|p| {
+                let mut map_clone = map.clone();
+                if map_clone[p] != Cell::Guard {
+                    map_clone[p] = Cell::Obstacle;
+                }
+                map_clone
+            })
+            .filter(|m| contains_loop(m, start))
+            .count()
+            .into())
+    }
+}
+
+fn find_start(map: &Grid<Cell>) -> Result<Vec2> {
+    map.iter_positions()
+        .find(|&p| map[p] == Cell::Guard)
+        .ok_or_else(|| Error::new("no guard '^' in the map"))
+}
+
+fn count_unique_positions(map: &Grid<Cell>, start: Vec2) -> HashSet<Vec2> {
+    let mut positions: HashSet<Vec2> = HashSet::new();
+
+    let mut direction = UP;
+    let mut pos = start;
+    loop {
+        let walk = map
+            .ray(pos, direction)
+            .take_while(|&(_, &cell)| cell != Cell::Obstacle);
+        for (p, _) in walk {
+            positions.insert(p);
+            pos = p;
+        }
+        if map.get(pos + direction).is_none() {
+            return positions;
+        }
+        direction = direction.clockwise();
+    }
+}
+
+fn contains_loop(map: &Grid<Cell>, start: Vec2) -> bool {
+    let mut breadcrumbs: HashMap<Vec2, Vec2> = HashMap::new();
+
+    let mut direction = UP;
+    let mut pos = start;
+    let mut next_pos = pos + direction;
+
+    while let Some(&next) = map.get(next_pos) {
+        if next == Cell::Obstacle {
+            direction = direction.clockwise();
+            next_pos = pos + direction;
+            continue;
+        }
+
+        match breadcrumbs.get(&pos) {
+            Some(crumb_dir) => {
+                if *crumb_dir == direction {
+                    return true;
+                }
+            }
+            None => {
+                breadcrumbs.insert(pos, direction);
+            }
+        }
+
+        pos = next_pos;
+        next_pos = pos + direction;
+    }
+
+    false
+}
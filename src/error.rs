@@ -0,0 +1,162 @@
+use std::{fmt, num::ParseIntError};
+
+use crate::input::InputError;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Error raised while loading, parsing or solving a puzzle.
+///
+/// Besides the reason it carries, when known, the puzzle and the 1-based line and
+/// column of the input it refers to.
+#[derive(Debug)]
+pub struct Error {
+    pub puzzle: Option<(u16, u8)>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub reason: String,
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl Error {
+    pub fn new(reason: impl Into<String>) -> Self {
+        Error {
+            puzzle: None,
+            line: None,
+            column: None,
+            reason: reason.into(),
+            source: None,
+        }
+    }
+
+    /// Points the error at `part`, which must be a slice of `input`.
+    ///
+    /// Leaves the position untouched if `part` does not belong to `input`.
+    pub fn at(self, input: &str, part: &str) -> Self {
+        let offset = (part.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        self.at_offset(input, offset)
+    }
+
+    /// Points the error at the character of `input` holding the byte at `offset`.
+    ///
+    /// Leaves the position untouched if `offset` is past the end of `input`.
+    pub fn at_offset(mut self, input: &str, mut offset: usize) -> Self {
+        if offset <= input.len() {
+            while !input.is_char_boundary(offset) {
+                offset -= 1;
+            }
+            let before = &input[..offset];
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            self.line = Some(before.matches('\n').count() + 1);
+            self.column = Some(before[line_start..].chars().count() + 1);
+        }
+        self
+    }
+
+    /// Sets the position of the error, keeping the current one where not given.
+    pub fn at_position(mut self, line: Option<usize>, column: Option<usize>) -> Self {
+        self.line = line.or(self.line);
+        self.column = column.or(self.column);
+        self
+    }
+
+    /// Sets the line of the error, for errors raised while parsing a single line.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn in_puzzle(mut self, year: u16, day: u8) -> Self {
+        self.puzzle = Some((year, day));
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((year, day)) = self.puzzle {
+            write!(f, "{} day {:02}, ", year, day)?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "line {}, ", line)?;
+        }
+        if let Some(column) = self.column {
+            write!(f, "column {}, ", column)?;
+        }
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|e| e as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(value: ParseIntError) -> Self {
+        let mut error = Error::new(format!("invalid number: {}", value));
+        error.source = Some(Box::new(value));
+        error
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(value: InputError) -> Self {
+        let mut error = Error::new(value.to_string());
+        error.source = Some(Box::new(value));
+        error
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at() {
+        let input = "abc\ndef\nghi";
+        let error = Error::new("bad").at(input, &input[5..]);
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn test_at_offset() {
+        let input = "ab\néx";
+        // inside 'é'
+        let error = Error::new("bad").at_offset(input, 4);
+        assert_eq!((error.line, error.column), (Some(2), Some(1)));
+        let error = Error::new("bad").at_offset(input, 5);
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+        let error = Error::new("bad").at_offset(input, 7);
+        assert_eq!((error.line, error.column), (None, None));
+    }
+
+    #[test]
+    fn test_at_foreign_slice() {
+        let error = Error::new("bad").at("abc", &String::from("abc"));
+        assert_eq!((error.line, error.column), (None, None));
+    }
+
+    #[test]
+    fn test_display() {
+        let input = "abc\ndef";
+        let error = Error::new("unexpected 'e'")
+            .at(input, &input[5..])
+            .on_line(7)
+            .in_puzzle(2024, 6);
+        assert_eq!(
+            error.to_string(),
+            "2024 day 06, line 7, column 2, unexpected 'e'"
+        );
+        assert_eq!(Error::new("empty input").to_string(), "empty input");
+    }
+
+    #[test]
+    fn test_from_parse_int() {
+        let error: Error = "x".parse::<u32>().unwrap_err().into();
+        assert!(error.reason.starts_with("invalid number"));
+        assert!(std::error::Error::source(&error).is_some());
+    }
+}
@@ -0,0 +1,356 @@
+use std::{fmt::Write, fs, io, path::PathBuf};
+
+use crate::{
+    answer::Answer,
+    answers::{to_answer, to_value},
+    error::{Error, Result},
+    input::{inputs_dir, load, Source},
+    solution::Solution,
+};
+
+/// An example input with the expected answers of the parts it covers.
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+/// Returns the path of an example file, `<inputs>/<year>/<day>.<suffix>.txt`.
+pub fn example_path(year: u16, day: u8, suffix: &str) -> PathBuf {
+    inputs_dir()
+        .join(year.to_string())
+        .join(format!("{:02}.{}.txt", day, suffix))
+}
+
+/// Reads an example file, see [`example_path`].
+pub fn load_example(year: u16, day: u8, suffix: &str) -> Result<String> {
+    Ok(load(
+        year,
+        day,
+        &Source::File(example_path(year, day, suffix)),
+    )?)
+}
+
+/// Returns the path of the expected answers of the saved examples of a day,
+/// `<inputs>/<year>/<day>.examples.toml`.
+///
+/// It holds one table per example file, named after its suffix:
+///
+/// ```toml
+/// [example]
+/// part1 = 142
+///
+/// [example2]
+/// part2 = 281
+/// ```
+pub fn records_path(year: u16, day: u8) -> PathBuf {
+    inputs_dir()
+        .join(year.to_string())
+        .join(format!("{:02}.examples.toml", day))
+}
+
+/// Reads the examples listed in the records of a day, see [`records_path`], a
+/// missing file listing none.
+pub fn load_saved(year: u16, day: u8) -> Result<Vec<Example>> {
+    let mut examples = load_records(year, day)?;
+    for example in &mut examples {
+        example.input = load_example(year, day, &example.name)?;
+    }
+    Ok(examples)
+}
+
+/// Reads the records of a day, leaving the inputs empty.
+fn load_records(year: u16, day: u8) -> Result<Vec<Example>> {
+    let path = records_path(year, day);
+    match fs::read_to_string(&path) {
+        Ok(text) => parse_records(&text).map_err(|e| {
+            Error::new(format!("{}: {}", path.display(), e.reason)).at_position(e.line, e.column)
+        }),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(Error::new(format!("cannot read {}: {}", path.display(), e))),
+    }
+}
+
+/// Parses the records of expected answers, leaving the inputs empty.
+fn parse_records(text: &str) -> Result<Vec<Example>> {
+    let table = text.parse::<toml::Table>().map_err(|e| {
+        let error = Error::new(e.message().to_owned());
+        match e.span() {
+            Some(span) => error.at(text, &text[span]),
+            None => error,
+        }
+    })?;
+
+    let mut examples = Vec::new();
+    for (name, parts) in &table {
+        let mut example = Example {
+            name: name.clone(),
+            input: String::new(),
+            part1: None,
+            part2: None,
+        };
+        let parts = parts
+            .as_table()
+            .ok_or_else(|| Error::new(format!("{} is not a table of parts", name)))?;
+        for (key, value) in parts {
+            let answer = to_answer(value).ok_or_else(|| {
+                Error::new(format!("{}.{} must be an integer or a string", name, key))
+            })?;
+            match key.as_str() {
+                "part1" => example.part1 = Some(answer),
+                "part2" => example.part2 = Some(answer),
+                _ => return Err(Error::new(format!("unknown key {}.{}", name, key))),
+            }
+        }
+        examples.push(example);
+    }
+    Ok(examples)
+}
+
+fn records(examples: &[Example]) -> String {
+    let mut records = String::new();
+    for (i, example) in examples.iter().enumerate() {
+        if i > 0 {
+            records.push('\n');
+        }
+        writeln!(records, "[{}]", example.name).unwrap();
+        for (part, answer) in [(1, &example.part1), (2, &example.part2)] {
+            if let Some(answer) = answer {
+                writeln!(records, "part{} = {}", part, to_value(answer)).unwrap();
+            }
+        }
+    }
+    records
+}
+
+/// Adds the extracted `examples` to the `recorded` ones, keeping the answers already
+/// recorded.
+fn merge(recorded: &mut Vec<Example>, examples: &[Example]) {
+    for example in examples {
+        match recorded.iter_mut().find(|e| e.name == example.name) {
+            Some(kept) => {
+                kept.part1 = kept.part1.take().or_else(|| example.part1.clone());
+                kept.part2 = kept.part2.take().or_else(|| example.part2.clone());
+            }
+            None => recorded.push(example.clone()),
+        }
+    }
+}
+
+/// Writes the example files of a day and the records of their expected answers,
+/// returning the written files.
+///
+/// Example files are only replaced when empty, and the records keep the examples
+/// and answers already there, so that edited examples are kept.
+pub fn save(year: u16, day: u8, examples: &[Example]) -> Result<Vec<PathBuf>> {
+    let mut recorded = load_records(year, day)?;
+    merge(&mut recorded, examples);
+    let records = records(&recorded);
+
+    let mut files = examples
+        .iter()
+        .map(|e| (example_path(year, day, &e.name), &e.input))
+        .collect::<Vec<_>>();
+    for (path, input) in &files {
+        match fs::read_to_string(path) {
+            Ok(saved) if !saved.is_empty() && saved != **input => {
+                return Err(Error::new(format!(
+                    "{} already holds another example",
+                    path.display()
+                )))
+            }
+            _ => {}
+        }
+    }
+    files.push((records_path(year, day), &records));
+
+    for (path, content) in &files {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| Error::new(format!("cannot create {}: {}", parent.display(), e)))?;
+        }
+        fs::write(path, content)
+            .map_err(|e| Error::new(format!("cannot write {}: {}", path.display(), e)))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Checks the solution against every saved example of a day, see [`load_saved`].
+///
+/// A day whose examples were not extracted yet has none to fail.
+pub fn check_saved<S: Solution>(solution: &S, year: u16, day: u8) -> Result<()> {
+    load_saved(year, day)?
+        .iter()
+        .try_for_each(|e| check(solution, e))
+        .map_err(|e| e.in_puzzle(year, day))
+}
+
+/// Runs the solution on the example, failing on the first part whose answer
+/// differs from the expected one.
+pub fn check<S: Solution>(solution: &S, example: &Example) -> Result<()> {
+    let input = solution
+        .parse(&example.input)
+        .map_err(|e| Error::new(format!("example {}: {}", example.name, e)))?;
+    for (part, expected) in [(1, &example.part1), (2, &example.part2)] {
+        let Some(expected) = expected else {
+            continue;
+        };
+        let answer = match part {
+            1 => solution.part1(&input),
+            _ => solution.part2(&input),
+        }
+        .map_err(|e| Error::new(format!("example {}, part {}: {}", example.name, part, e)))?;
+        if answer != *expected {
+            return Err(Error::new(format!(
+                "example {}, part {}: expected {}, got {}",
+                example.name, part, expected, answer
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Declares one test per example of a day.
+///
+/// Each example reads `inputs/<year>/<day>.<suffix>.txt` with `file("<suffix>")`,
+/// or takes the input inline, and lists the expected answer of the parts it covers:
+///
+/// ```ignore
+/// examples! {
+///     Day, 2023, 1;
+///     example: file("example") => part1 = 142;
+///     words: "two1nine\neightwothree" => part2 = 112;
+/// }
+/// ```
+#[macro_export]
+macro_rules! examples {
+    (
+        $solution:expr, $year:literal, $day:literal;
+        $($name:ident: $input:tt $(($suffix:literal))? => $($part:ident = $answer:expr),+;)+
+    ) => {$(
+        #[test]
+        fn $name() {
+            let mut example = $crate::examples::Example {
+                name: stringify!($name).to_owned(),
+                input: $crate::examples!(@input $year, $day, $input $(($suffix))?),
+                part1: None,
+                part2: None,
+            };
+            $(example.$part = Some($crate::answer::Answer::from($answer));)+
+            if let Err(error) = $crate::examples::check(&$solution, &example) {
+                panic!("{}", error);
+            }
+        }
+    )+};
+    (@input $year:literal, $day:literal, file($suffix:literal)) => {
+        match $crate::examples::load_example($year, $day, $suffix) {
+            Ok(input) => input,
+            Err(error) => panic!("{}", error),
+        }
+    };
+    (@input $year:literal, $day:literal, $inline:literal) => {
+        $inline.to_owned()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        fn parse(&self, input: &str) -> Result<Self::Input> {
+            input.split_whitespace().map(|n| Ok(n.parse()?)).collect()
+        }
+
+        fn part1(&self, input: &Self::Input) -> Result<Answer> {
+            Ok(input.iter().sum::<u32>().into())
+        }
+
+        fn part2(&self, input: &Self::Input) -> Result<Answer> {
+            Ok(input.iter().product::<u32>().into())
+        }
+    }
+
+    fn example(part1: Option<u32>, part2: Option<u32>) -> Example {
+        Example {
+            name: "test".to_owned(),
+            input: "2 3 4".to_owned(),
+            part1: part1.map(Answer::from),
+            part2: part2.map(Answer::from),
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        assert!(check(&Sum, &example(Some(9), Some(24))).is_ok());
+        assert!(check(&Sum, &example(None, Some(24))).is_ok());
+    }
+
+    #[test]
+    fn test_check_mismatch() {
+        let error = check(&Sum, &example(Some(9), Some(25))).unwrap_err();
+        assert_eq!(error.reason, "example test, part 2: expected 25, got 24");
+    }
+
+    #[test]
+    fn test_records_round_trip() {
+        let examples = [
+            example(Some(9), None),
+            Example {
+                name: "example2".to_owned(),
+                input: String::new(),
+                part1: None,
+                part2: Some(Answer::from("ab,cd")),
+            },
+        ];
+        let text = records(&examples);
+        assert_eq!(text, "[test]\npart1 = 9\n\n[example2]\npart2 = \"ab,cd\"\n");
+        // tables come back sorted by name
+        let parsed = parse_records(&text).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].name, "example2");
+        assert_eq!(parsed[0].part2, Some(Answer::from("ab,cd")));
+        assert_eq!(parsed[1].name, "test");
+        assert_eq!(parsed[1].part1, Some(Answer::from(9)));
+
+        let error = parse_records("[example]\npart3 = 1\n").unwrap_err();
+        assert_eq!(error.reason, "unknown key example.part3");
+    }
+
+    #[test]
+    fn test_merge_keeps_recorded() {
+        let mut recorded = parse_records("[example]\npart1 = 10\n\n[words]\npart2 = 3\n").unwrap();
+        merge(
+            &mut recorded,
+            &[
+                Example {
+                    name: "example".to_owned(),
+                    ..example(Some(9), Some(24))
+                },
+                example(Some(9), None),
+            ],
+        );
+        let text = records(&recorded);
+        assert_eq!(
+            text,
+            "[example]\npart1 = 10\npart2 = 24\n\n[words]\npart2 = 3\n\n[test]\npart1 = 9\n"
+        );
+    }
+
+    #[test]
+    fn test_example_path() {
+        assert!(example_path(2023, 1, "example2").ends_with("2023/01.example2.txt"));
+    }
+
+    examples! {
+        Sum, 2023, 1;
+        inline_both: "1 2 3" => part1 = 6, part2 = 6;
+        inline_part2: "5 5" => part2 = 25;
+    }
+}
@@ -0,0 +1,252 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    answer::Answer,
+    client::{Hint, Outcome},
+    error::{Error, Result},
+};
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    TooHigh,
+    TooLow,
+    /// Wrong, without telling in which direction.
+    Wrong,
+}
+
+impl Verdict {
+    /// Returns the verdict of a reply, `None` when the answer was not checked.
+    pub fn of(outcome: &Outcome) -> Option<Self> {
+        match outcome {
+            Outcome::Correct => Some(Verdict::Right),
+            Outcome::Incorrect { hint, .. } => Some(match hint {
+                Some(Hint::TooHigh) => Verdict::TooHigh,
+                Some(Hint::TooLow) => Verdict::TooLow,
+                None => Verdict::Wrong,
+            }),
+            Outcome::TooSoon { .. } | Outcome::WrongLevel => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Verdict::Right => "right",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+        })
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "right" => Ok(Verdict::Right),
+            "too high" => Ok(Verdict::TooHigh),
+            "too low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => Err(Error::new(format!("unknown verdict {:?}", s))),
+        }
+    }
+}
+
+/// A submitted answer and its verdict.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Guess {
+    pub answer: Answer,
+    pub verdict: Verdict,
+}
+
+/// Numbers not yet ruled out by the answers found too low or too high.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bounds {
+    /// Smallest feasible answer.
+    pub min: Option<i128>,
+    /// Largest feasible answer.
+    pub max: Option<i128>,
+    /// Set when a guess at the limit of the numbers rules out every answer.
+    pub exhausted: bool,
+}
+
+impl Bounds {
+    pub fn of(guesses: &[Guess]) -> Self {
+        guesses.iter().fold(Bounds::default(), |bounds, guess| {
+            match (guess.verdict, guess.answer.as_number()) {
+                (Verdict::TooLow, Some(n)) => match n.checked_add(1) {
+                    Some(min) => Bounds {
+                        min: bounds.min.max(Some(min)),
+                        ..bounds
+                    },
+                    None => Bounds {
+                        exhausted: true,
+                        ..bounds
+                    },
+                },
+                (Verdict::TooHigh, Some(n)) => match n.checked_sub(1) {
+                    Some(max) => Bounds {
+                        max: Some(bounds.max.map_or(max, |known| known.min(max))),
+                        ..bounds
+                    },
+                    None => Bounds {
+                        exhausted: true,
+                        ..bounds
+                    },
+                },
+                _ => bounds,
+            }
+        })
+    }
+
+    /// Returns whether the guesses contradict each other or leave no number.
+    pub fn is_empty(&self) -> bool {
+        self.exhausted || matches!((self.min, self.max), (Some(min), Some(max)) if min > max)
+    }
+
+    pub fn contains(&self, n: i128) -> bool {
+        !self.is_empty()
+            && self.min.is_none_or(|min| n >= min)
+            && self.max.is_none_or(|max| n <= max)
+    }
+}
+
+impl fmt::Display for Bounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "no answer");
+        }
+        match (self.min, self.max) {
+            (None, None) => write!(f, "any answer"),
+            (Some(min), None) => write!(f, "{}..", min),
+            (None, Some(max)) => write!(f, "..={}", max),
+            (Some(min), Some(max)) => write!(f, "{}..={}", min, max),
+        }
+    }
+}
+
+/// Fails if `answer` is already known to be wrong from the earlier guesses.
+pub fn check(guesses: &[Guess], answer: &Answer) -> Result<()> {
+    if let Some(guess) = guesses.iter().find(|g| g.answer == *answer) {
+        return Err(Error::new(format!(
+            "{} was already submitted: {}",
+            answer, guess.verdict
+        )));
+    }
+    let bounds = Bounds::of(guesses);
+    if bounds.is_empty() {
+        return Err(Error::new("the earlier guesses rule out every answer"));
+    }
+    match answer.as_number() {
+        Some(n) if !bounds.contains(n) => Err(Error::new(format!(
+            "{} is outside the feasible answers {}",
+            answer, bounds
+        ))),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guess(answer: i64, verdict: Verdict) -> Guess {
+        Guess {
+            answer: Answer::from(answer),
+            verdict,
+        }
+    }
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(Bounds::of(&[]).to_string(), "any answer");
+        let guesses = [
+            guess(5100, Verdict::TooHigh),
+            guess(4000, Verdict::TooLow),
+            guess(5200, Verdict::TooHigh),
+            guess(3000, Verdict::TooLow),
+            guess(4500, Verdict::Wrong),
+        ];
+        let bounds = Bounds::of(&guesses);
+        assert_eq!(bounds.to_string(), "4001..=5099");
+        assert!(bounds.contains(4001) && bounds.contains(5099));
+        assert!(!bounds.contains(4000) && !bounds.contains(5100));
+        assert_eq!(Bounds::of(&guesses[..1]).to_string(), "..=5099");
+    }
+
+    #[test]
+    fn test_bounds_at_limits() {
+        let number = |n: i128, verdict| Guess {
+            answer: Answer::Signed(n),
+            verdict,
+        };
+        let bounds = Bounds::of(&[number(i128::MAX, Verdict::TooLow)]);
+        assert!(bounds.is_empty() && !bounds.contains(i128::MAX));
+        assert_eq!(bounds.to_string(), "no answer");
+        let bounds = Bounds::of(&[number(i128::MIN, Verdict::TooHigh)]);
+        assert!(bounds.is_empty());
+
+        let bounds = Bounds::of(&[
+            number(0, Verdict::TooHigh),
+            number(u64::MAX as i128, Verdict::TooLow),
+        ]);
+        assert_eq!(bounds.to_string(), "no answer");
+        assert!(!bounds.contains(5));
+        let error = check(
+            &[number(1, Verdict::TooHigh), number(1, Verdict::TooLow)],
+            &Answer::from("abc"),
+        )
+        .unwrap_err();
+        assert_eq!(error.reason, "the earlier guesses rule out every answer");
+        assert_eq!(
+            Bounds::of(&[number(0, Verdict::TooHigh)]).to_string(),
+            "..=-1"
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let guesses = [
+            guess(5100, Verdict::TooHigh),
+            guess(4500, Verdict::Wrong),
+            Guess {
+                answer: Answer::from("abc"),
+                verdict: Verdict::Wrong,
+            },
+        ];
+        assert!(check(&guesses, &Answer::from(5086)).is_ok());
+        assert!(check(&guesses, &Answer::from("abd")).is_ok());
+        assert_eq!(
+            check(&guesses, &Answer::from(5100)).unwrap_err().reason,
+            "5100 was already submitted: too high"
+        );
+        assert!(check(&guesses, &Answer::from("4500")).is_err());
+        assert!(check(&guesses, &Answer::from("abc")).is_err());
+        assert_eq!(
+            check(&guesses, &Answer::from(6000)).unwrap_err().reason,
+            "6000 is outside the feasible answers ..=5099"
+        );
+    }
+
+    #[test]
+    fn test_verdict() {
+        let outcome = Outcome::Incorrect {
+            hint: Some(Hint::TooLow),
+            wait: None,
+        };
+        assert_eq!(Verdict::of(&outcome), Some(Verdict::TooLow));
+        assert_eq!(Verdict::of(&Outcome::WrongLevel), None);
+        for verdict in [
+            Verdict::Right,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+        ] {
+            assert_eq!(verdict.to_string().parse::<Verdict>().unwrap(), verdict);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use std::{
+    env, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+/// Environment variable overriding where the input is read from, either a path or `-` for stdin.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+/// Environment variable overriding the directory holding `<year>/<day>.txt` inputs.
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `<year>/<day>.txt` inside the inputs directory.
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Reads the source from [`INPUT_VAR`], falling back to [`Source::Default`].
+    pub fn from_env() -> Self {
+        env::var(INPUT_VAR)
+            .ok()
+            .filter(|v| !v.is_empty())
+            .map_or(Source::Default, |v| v.parse().unwrap())
+    }
+}
+
+impl FromStr for Source {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Source::Stdin,
+            path => Source::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Default => write!(f, "the inputs directory"),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+/// The input of a puzzle could not be read.
+#[derive(Debug)]
+pub struct InputError {
+    pub year: u16,
+    pub day: u8,
+    pub path: Option<PathBuf>,
+    pub source: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot read the input of {} day {:02} from ",
+            self.year, self.day
+        )?;
+        match &self.path {
+            Some(path) => write!(f, "{}", path.display())?,
+            None => write!(f, "stdin")?,
+        }
+        write!(f, ": {}", self.source)?;
+        if self.source.kind() == io::ErrorKind::NotFound {
+            write!(
+                f,
+                " (save the input there, or point {} to another file or `-` for stdin)",
+                INPUT_VAR
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// Returns the directory holding the inputs, [`INPUTS_DIR_VAR`] or the crate's `inputs/`.
+pub fn inputs_dir() -> PathBuf {
+    env::var_os(INPUTS_DIR_VAR)
+        .filter(|v| !v.is_empty())
+        .map_or_else(
+            || PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("inputs"),
+            PathBuf::from,
+        )
+}
+
+/// Returns the path of the input file of a puzzle, `<inputs>/<year>/<day>.txt`.
+pub fn default_path(year: u16, day: u8) -> PathBuf {
+    path_in(&inputs_dir(), year, day)
+}
+
+/// Returns the path of the input file of a puzzle under another inputs directory.
+pub fn path_in(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("{:02}.txt", day))
+}
+
+/// Reads the input of a puzzle from the given source.
+pub fn load(year: u16, day: u8, source: &Source) -> Result<String, InputError> {
+    let path = match source {
+        Source::Default => Some(default_path(year, day)),
+        Source::File(path) => Some(path.clone()),
+        Source::Stdin => None,
+    };
+    let read = match &path {
+        Some(path) => fs::read_to_string(path),
+        None => {
+            let mut buffer = String::new();
+            io::stdin().read_to_string(&mut buffer).map(|_| buffer)
+        }
+    };
+    read.map_err(|source| InputError {
+        year,
+        day,
+        path,
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_path() {
+        let path = default_path(2024, 6);
+        assert!(path.ends_with("2024/06.txt"));
+    }
+
+    #[test]
+    fn test_source_from_str() {
+        assert_eq!("-".parse(), Ok(Source::Stdin));
+        assert_eq!("a.txt".parse(), Ok(Source::File(PathBuf::from("a.txt"))));
+    }
+
+    #[test]
+    fn test_load_file() {
+        let path = env::temp_dir().join("aoc-input-test-load.txt");
+        fs::write(&path, "42\n").unwrap();
+        assert_eq!(load(2024, 1, &Source::File(path.clone())).unwrap(), "42\n");
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_load_missing() {
+        let path = PathBuf::from("does/not/exist.txt");
+        let error = load(2024, 1, &Source::File(path)).unwrap_err();
+        assert_eq!(error.source.kind(), io::ErrorKind::NotFound);
+        let message = error.to_string();
+        assert!(message.starts_with("cannot read the input of 2024 day 01 from does/not/exist.txt"));
+        assert!(message.contains(INPUT_VAR));
+    }
+}
@@ -1,2 +1,14 @@
 #![allow(dead_code)]
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod error;
+pub mod examples;
+pub mod guesses;
+pub mod input;
+pub mod puzzle;
+pub mod scaffold;
+pub mod solution;
 pub mod utils;
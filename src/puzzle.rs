@@ -0,0 +1,152 @@
+use std::{fs, path::PathBuf};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::{
+    error::{Error, Result},
+    examples::Example,
+    input::inputs_dir,
+};
+
+lazy_static! {
+    static ref ARTICLE: Regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    static ref PRE_CODE: Regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    static ref CODE_EM: Regex = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+}
+
+/// Returns the path of the saved description of a puzzle, `<inputs>/<year>/<day>.html`.
+pub fn description_path(year: u16, day: u8) -> PathBuf {
+    inputs_dir()
+        .join(year.to_string())
+        .join(format!("{:02}.html", day))
+}
+
+/// Reads the saved description of a puzzle, see [`description_path`].
+pub fn load_description(year: u16, day: u8) -> Result<String> {
+    let path = description_path(year, day);
+    fs::read_to_string(&path).map_err(|e| {
+        Error::new(format!(
+            "cannot read {}: {} (save the puzzle page there, or run `aoc fetch`)",
+            path.display(),
+            e
+        ))
+    })
+}
+
+/// Extracts the examples of a puzzle page with their expected answers.
+///
+/// Each part is described in its own `<article>`: its example is the first
+/// `<pre><code>` block, or the previous part's when it has none, and its expected
+/// answer the last `<code><em>` snippet. Examples are named `example`, `example2`...
+pub fn extract(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = Vec::new();
+    for (part, article) in articles(html).take(2).enumerate() {
+        let block = PRE_CODE.captures(article).map(|c| text(&c[1]));
+        let answer = CODE_EM
+            .captures_iter(article)
+            .last()
+            .map(|c| text(&c[1]).parse().unwrap());
+
+        let example = match block {
+            Some(block) => match examples.iter().position(|e| e.input == block) {
+                Some(i) => &mut examples[i],
+                None => {
+                    let name = match examples.len() {
+                        0 => "example".to_owned(),
+                        n => format!("example{}", n + 1),
+                    };
+                    examples.push(Example {
+                        name,
+                        input: block,
+                        part1: None,
+                        part2: None,
+                    });
+                    examples.last_mut().unwrap()
+                }
+            },
+            None => match examples.last_mut() {
+                Some(example) => example,
+                None => continue,
+            },
+        };
+        match part {
+            0 => example.part1 = answer,
+            _ => example.part2 = answer,
+        }
+    }
+    examples
+}
+
+/// Returns the contents of the `<article>` elements of a page, one per part.
+pub(crate) fn articles(html: &str) -> impl Iterator<Item = &str> {
+    ARTICLE
+        .captures_iter(html)
+        .map(|c| c.get(1).unwrap().as_str())
+}
+
+/// Strips the tags of an HTML fragment and decodes its entities.
+pub(crate) fn text(html: &str) -> String {
+    TAG.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Test ---</h2>
+<p>For example:</p>
+<pre><code>1abc2
+<em>a&lt;b</em> &amp; c
+</code></pre>
+<p>Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>55017</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>It looks like <code>one</code> counts, for example:</p>
+<pre><code>two1nine
+</code></pre>
+<p>With <code><em>29</em></code> on the first line, the sum is <code><em>281</em></code>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn test_extract() {
+        let examples = extract(PAGE);
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].name, "example");
+        assert_eq!(examples[0].input, "1abc2\na<b & c\n");
+        assert_eq!(examples[0].part1, Some(Answer::from(142)));
+        assert_eq!(examples[0].part2, None);
+        assert_eq!(examples[1].name, "example2");
+        assert_eq!(examples[1].input, "two1nine\n");
+        assert_eq!(examples[1].part2, Some(Answer::from(281)));
+    }
+
+    #[test]
+    fn test_extract_shared_example() {
+        let page = PAGE.replace("<pre><code>two1nine\n</code></pre>", "");
+        let examples = extract(&page);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].part1, Some(Answer::from(142)));
+        assert_eq!(examples[0].part2, Some(Answer::from(281)));
+    }
+
+    #[test]
+    fn test_extract_part1_only() {
+        let page = &PAGE[..PAGE.find("<p>Your puzzle").unwrap()];
+        let examples = extract(page);
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].part2, None);
+        assert!(extract("<main></main>").is_empty());
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    fmt, fs,
+    ops::Range,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::error::{Error, Result};
+
+/// Shape of the solution generated for a new day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Template {
+    /// One record per line, parsed with `FromStr`, as in y2024d02.
+    #[default]
+    Lines,
+    /// A `Grid<char>` map, as in y2024d06.
+    Grid,
+    /// Blocks split by blank lines, as in y2023d05.
+    Sections,
+}
+
+impl Template {
+    pub const ALL: [Template; 3] = [Template::Lines, Template::Grid, Template::Sections];
+
+    fn source(&self) -> &'static str {
+        match self {
+            Template::Lines => include_str!("../templates/lines.rs"),
+            Template::Grid => include_str!("../templates/grid.rs"),
+            Template::Sections => include_str!("../templates/sections.rs"),
+        }
+    }
+}
+
+impl FromStr for Template {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Template::ALL
+            .into_iter()
+            .find(|t| t.to_string() == s)
+            .ok_or_else(|| {
+                Error::new(format!(
+                    "unknown template {:?}, expected lines, grid or sections",
+                    s
+                ))
+            })
+    }
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Template::Lines => write!(f, "lines"),
+            Template::Grid => write!(f, "grid"),
+            Template::Sections => write!(f, "sections"),
+        }
+    }
+}
+
+pub fn module_name(year: u16, day: u8) -> String {
+    format!("y{}d{:02}", year, day)
+}
+
+/// Creates the solution, test, input and example files of a new day under `root` and
+/// registers it, returning the created files.
+///
+/// Input and example files already there, e.g. fetched, are kept. Nothing is written
+/// if the solution or test file already exists or the day is registered.
+pub fn scaffold(root: &Path, year: u16, day: u8, template: Template) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::new(format!("invalid day {}", day)));
+    }
+    let module = module_name(year, day);
+    let registry_path = root.join("src/days/mod.rs");
+    let registry = fs::read_to_string(&registry_path)
+        .map_err(|e| Error::new(format!("cannot read {}: {}", registry_path.display(), e)))?;
+    let registry = register(&registry, year, day)?;
+
+    let code = [
+        (
+            root.join(format!("src/days/{}.rs", module)),
+            template.source().to_owned(),
+        ),
+        (
+            root.join(format!("tests/{}.rs", module)),
+            render_test(year, day),
+        ),
+    ];
+    if let Some((path, _)) = code.iter().find(|(path, _)| path.exists()) {
+        return Err(Error::new(format!("{} already exists", path.display())));
+    }
+    let inputs = [
+        root.join(format!("inputs/{}/{:02}.txt", year, day)),
+        root.join(format!("inputs/{}/{:02}.example.txt", year, day)),
+    ]
+    .into_iter()
+    .filter(|path| !path.exists())
+    .map(|path| (path, String::new()));
+
+    let files = code.into_iter().chain(inputs).collect::<Vec<_>>();
+    for (path, content) in &files {
+        write(path, content)?;
+    }
+    write(&registry_path, &registry)?;
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| Error::new(format!("cannot create {}: {}", parent.display(), e)))?;
+    }
+    fs::write(path, content)
+        .map_err(|e| Error::new(format!("cannot write {}: {}", path.display(), e)))
+}
+
+fn render_test(year: u16, day: u8) -> String {
+    include_str!("../templates/test.rs")
+        .replace("{{module}}", &module_name(year, day))
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Declares the day module and adds it to the `register!` block of the days
+/// module, keeping both sorted.
+fn register(registry: &str, year: u16, day: u8) -> Result<String> {
+    let module = module_name(year, day);
+    let declaration = format!("pub mod {};", module);
+    if registry.lines().any(|l| l == declaration) {
+        return Err(Error::new(format!("{} is already registered", module)));
+    }
+
+    let start = registry
+        .find("\npub mod ")
+        .ok_or_else(|| Error::new("no day modules in the days module"))?
+        + 1;
+    let end = start
+        + registry[start..]
+            .lines()
+            .take_while(|l| l.starts_with("pub mod "))
+            .map(|l| l.len() + 1)
+            .sum::<usize>()
+        - 1;
+    let registry = insert_sorted(registry, start..end, &declaration);
+
+    let start = registry
+        .find("register! {\n")
+        .ok_or_else(|| Error::new("no register! block in the days module"))?
+        + "register! {\n".len();
+    let end = start
+        + registry[start..]
+            .find("\n}")
+            .ok_or_else(|| Error::new("unterminated register! block"))?;
+    if registry[start..end]
+        .lines()
+        .any(|l| l.trim_start().starts_with(&format!("{} ", module)))
+    {
+        return Err(Error::new(format!("{} is already registered", module)));
+    }
+    let entry = format!("    {} => ({}, {}),", module, year, day);
+    Ok(insert_sorted(&registry, start..end, &entry))
+}
+
+/// Inserts `line` among the lines of `text` in `block`, before the first greater one.
+fn insert_sorted(text: &str, block: Range<usize>, line: &str) -> String {
+    let mut lines = text[block.clone()].lines().collect::<Vec<_>>();
+    let position = lines
+        .iter()
+        .position(|l| l.trim_start() > line.trim_start())
+        .unwrap_or(lines.len());
+    lines.insert(position, line);
+    format!(
+        "{}{}{}",
+        &text[..block.start],
+        lines.join("\n"),
+        &text[block.end..]
+    )
+}
+
+// The solution templates are compiled here so that they never rot.
+#[cfg(test)]
+#[path = "../templates/lines.rs"]
+mod lines_template;
+
+#[cfg(test)]
+#[path = "../templates/grid.rs"]
+mod grid_template;
+
+#[cfg(test)]
+#[path = "../templates/sections.rs"]
+mod sections_template;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Solution;
+
+    const REGISTRY: &str = "use x;\n\npub mod y2023d01;\npub mod y2024d06;\n\n\
+        register! {\n    y2023d01 => (2023, 1),\n    y2024d06 => (2024, 6),\n}\n";
+
+    #[test]
+    fn test_register_sorted() {
+        let registry = register(REGISTRY, 2024, 2).unwrap();
+        assert_eq!(
+            registry,
+            "use x;\n\npub mod y2023d01;\npub mod y2024d02;\npub mod y2024d06;\n\n\
+            register! {\n    y2023d01 => (2023, 1),\n    y2024d02 => (2024, 2),\n    y2024d06 => (2024, 6),\n}\n"
+        );
+        let registry = register(REGISTRY, 2024, 7).unwrap();
+        assert!(registry.contains("pub mod y2024d06;\npub mod y2024d07;\n\n"));
+        assert!(registry.ends_with("    y2024d06 => (2024, 6),\n    y2024d07 => (2024, 7),\n}\n"));
+    }
+
+    #[test]
+    fn test_register_twice() {
+        assert!(register(REGISTRY, 2024, 6).is_err());
+    }
+
+    #[test]
+    fn test_render_test() {
+        let test = render_test(2024, 7);
+        assert!(test.contains("days::y2024d07::Day"));
+        assert!(test.contains("check_saved(&Day, 2024, 7)"));
+        assert!(test.contains("check(2024, 7)"));
+        assert!(!test.contains("{{"));
+    }
+
+    #[test]
+    fn test_template_from_str() {
+        for template in Template::ALL {
+            assert_eq!(template.to_string().parse::<Template>().unwrap(), template);
+        }
+        assert!("maze".parse::<Template>().is_err());
+    }
+
+    #[test]
+    fn test_templates_run() {
+        assert!(lines_template::Day.solve("1 2\n3").is_ok());
+        assert!(grid_template::Day.solve("#.\n.#").is_ok());
+        assert!(sections_template::Day.solve("a\n\nb").is_ok());
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = std::env::temp_dir().join("aoc-scaffold-test");
+        let _ = fs::remove_dir_all(&root);
+        write(&root.join("src/days/mod.rs"), REGISTRY).unwrap();
+
+        let files = scaffold(&root, 2024, 7, Template::Grid).unwrap();
+        assert_eq!(files.len(), 4);
+        assert!(files.iter().all(|f| f.exists()));
+        let solution = fs::read_to_string(root.join("src/days/y2024d07.rs")).unwrap();
+        assert_eq!(solution, Template::Grid.source());
+        let registry = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+        assert!(registry.contains("y2024d07 => (2024, 7),"));
+
+        // refuses to overwrite
+        fs::write(root.join("src/days/y2024d08.rs"), "work").unwrap();
+        assert!(scaffold(&root, 2024, 7, Template::Grid).is_err());
+        assert!(scaffold(&root, 2024, 8, Template::Lines).is_err());
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/y2024d08.rs")).unwrap(),
+            "work"
+        );
+        assert!(!root.join("tests/y2024d08.rs").exists());
+
+        // keeps a fetched input
+        write(&root.join("inputs/2024/09.txt"), "1 2\n").unwrap();
+        let files = scaffold(&root, 2024, 9, Template::Lines).unwrap();
+        assert_eq!(files.len(), 3);
+        assert!(!files.contains(&root.join("inputs/2024/09.txt")));
+        assert_eq!(
+            fs::read_to_string(root.join("inputs/2024/09.txt")).unwrap(),
+            "1 2\n"
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
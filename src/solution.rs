@@ -0,0 +1,61 @@
+use std::any::Any;
+
+use crate::{answer::Answer, error::Result};
+
+/// A puzzle solution split into a parsing phase shared by two independent parts.
+pub trait Solution {
+    /// The parsed representation of the puzzle input.
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer>;
+
+    /// Parses the input once and solves both parts on it.
+    fn solve(&self, input: &str) -> Result<(Answer, Answer)> {
+        let input = self.parse(input)?;
+        Ok((self.part1(&input)?, self.part2(&input)?))
+    }
+}
+
+/// Object safe counterpart of [`Solution`], so that days with different input
+/// types can be stored side by side.
+///
+/// The parsed input is passed around as `dyn Any` and must come from `parse_any`
+/// of the same solution.
+pub trait AnySolution: Sync {
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    fn part1_any(&self, input: &dyn Any) -> Result<Answer>;
+
+    fn part2_any(&self, input: &dyn Any) -> Result<Answer>;
+}
+
+impl<S> AnySolution for S
+where
+    S: Solution + Sync,
+    S::Input: 'static,
+{
+    fn parse_any(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn part1_any(&self, input: &dyn Any) -> Result<Answer> {
+        self.part1(downcast::<S>(input))
+    }
+
+    fn part2_any(&self, input: &dyn Any) -> Result<Answer> {
+        self.part2(downcast::<S>(input))
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    input
+        .downcast_ref()
+        .expect("Input parsed by a different solution")
+}
@@ -1,7 +1,12 @@
-use std::str::FromStr;
+use std::{fmt::Display, str::FromStr};
+
+use crate::error::{Error, Result};
 
 pub trait Parser {
     fn to_vec<T: FromStr>(self, separator: &str) -> Vec<T>;
+
+    /// Parses every non empty line, reporting the line number of the first failure.
+    fn parse_lines<T: FromStr<Err = Error>>(self) -> Result<Vec<T>>;
 }
 
 impl Parser for &str {
@@ -10,4 +15,64 @@ impl Parser for &str {
             .filter_map(|x| x.parse::<T>().ok())
             .collect()
     }
+
+    fn parse_lines<T: FromStr<Err = Error>>(self) -> Result<Vec<T>> {
+        self.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| line.parse().map_err(|e: Error| e.on_line(i + 1)))
+            .collect()
+    }
+}
+
+/// Parses `part`, a slice of `input`, pointing the error at it on failure.
+pub fn parse_at<T>(input: &str, part: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    part.parse()
+        .map_err(|e| Error::new(format!("invalid value {:?}: {}", part, e)).at(input, part))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Even(u32);
+
+    impl FromStr for Even {
+        type Err = Error;
+
+        fn from_str(s: &str) -> Result<Self> {
+            let n: u32 = parse_at(s, s.trim())?;
+            if n.is_multiple_of(2) {
+                Ok(Even(n))
+            } else {
+                Err(Error::new("odd number").at(s, s.trim()))
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let values = "2\n\n4\n".parse_lines::<Even>().unwrap();
+        assert_eq!(values.iter().map(|e| e.0).collect::<Vec<_>>(), vec![2, 4]);
+    }
+
+    #[test]
+    fn test_parse_lines_error_position() {
+        let error = "2\n4\n  7".parse_lines::<Even>().unwrap_err();
+        assert_eq!((error.line, error.column), (Some(3), Some(3)));
+        assert_eq!(error.reason, "odd number");
+    }
+
+    #[test]
+    fn test_parse_at() {
+        let input = "12 x4";
+        let error = parse_at::<u32>(input, &input[3..]).unwrap_err();
+        assert_eq!((error.line, error.column), (Some(1), Some(4)));
+        assert_eq!(parse_at::<u32>(input, &input[..2]).unwrap(), 12);
+    }
 }
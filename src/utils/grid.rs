@@ -1,6 +1,29 @@
-use std::ops::{Index, IndexMut};
+use std::{
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
 
-use super::point::Vec2;
+use super::{point::Vec2, tile::Tile};
+use crate::error::{Error, Result};
+
+mod lines;
+mod neighbors;
+mod path;
+mod pattern;
+mod regions;
+mod sparse;
+mod sub_grid;
+mod transform;
+mod view;
+
+pub use lines::Ray;
+pub use neighbors::Neighborhood;
+pub use path::Paths;
+pub use pattern::Symmetry;
+pub use regions::{Region, RegionId, Regions};
+pub use sparse::SparseGrid;
+pub use sub_grid::SubGrid;
+pub use view::{Outside, View};
 
 #[derive(Debug, Clone)]
 pub struct Grid<T> {
@@ -22,15 +45,38 @@ impl<T: std::fmt::Display> std::fmt::Display for Grid<T> {
 }
 
 impl<T> Grid<T> {
+    /// Builds a grid from its rows.
+    ///
+    /// # Panics
+    ///
+    /// If the rows are of different lengths, see [`Grid::try_from_matrix`].
     pub fn from_matrix(matrix: Vec<Vec<T>>) -> Self {
+        Self::try_from_matrix(matrix).expect("Rows of different lengths")
+    }
+
+    /// Builds a grid from its rows, failing on the first row whose length differs
+    /// from the first one, with that row as the error line.
+    pub fn try_from_matrix(matrix: Vec<Vec<T>>) -> Result<Self> {
         let height = matrix.len();
-        let width = matrix.get(0).map_or(0, |row| row.len());
+        let width = matrix.first().map_or(0, |row| row.len());
+        if let Some((y, row)) = matrix
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.len() != width)
+        {
+            return Err(Error::new(format!(
+                "row of {} cells, expected {} as in the first row",
+                row.len(),
+                width
+            ))
+            .on_line(y + 1));
+        }
         let data = matrix.into_iter().flatten().collect();
-        Grid {
+        Ok(Grid {
             width,
             height,
             data,
-        }
+        })
     }
 
     pub fn from_vec(width: usize, height: usize, data: Vec<T>) -> Self {
@@ -42,10 +88,104 @@ impl<T> Grid<T> {
         }
     }
 
+    /// Parses one cell per character, one row per line, trailing blank lines aside.
+    ///
+    /// Errors from `cell` are positioned at its character unless they have a
+    /// position already; rows of different lengths are rejected.
+    pub fn parse_with<F>(input: &str, mut cell: F) -> Result<Self>
+    where
+        F: FnMut(char, Vec2) -> Result<T>,
+    {
+        Self::parse_rows(input, |line, y| {
+            line.char_indices()
+                .enumerate()
+                .map(|(x, (i, c))| {
+                    cell(c, Vec2::new(x as i32, y as i32)).map_err(|e| match e.line {
+                        Some(_) => e,
+                        None => e.at(input, &line[i..]),
+                    })
+                })
+                .collect()
+        })
+    }
+
+    /// Like [`Grid::parse_with`], one cell per byte, for ASCII inputs.
+    pub fn parse_bytes_with<F>(input: &str, mut cell: F) -> Result<Self>
+    where
+        F: FnMut(u8, Vec2) -> Result<T>,
+    {
+        Self::parse_rows(input, |line, y| {
+            let start = line.as_ptr() as usize - input.as_ptr() as usize;
+            line.bytes()
+                .enumerate()
+                .map(|(x, b)| {
+                    cell(b, Vec2::new(x as i32, y as i32)).map_err(|e| match e.line {
+                        Some(_) => e,
+                        None => e.at_offset(input, start + x),
+                    })
+                })
+                .collect()
+        })
+    }
+
+    fn parse_rows<F>(input: &str, mut row: F) -> Result<Self>
+    where
+        F: FnMut(&str, usize) -> Result<Vec<T>>,
+    {
+        let lines = input.trim_end_matches(['\n', '\r']).lines();
+        let (mut width, mut height, mut data) = (0, 0, Vec::new());
+        for (y, line) in lines.enumerate() {
+            let cells = row(line, y)?;
+            if y == 0 {
+                width = cells.len();
+            } else if cells.len() != width {
+                return Err(Error::new(format!(
+                    "row of {} cells, expected {} as in the first row",
+                    cells.len(),
+                    width
+                ))
+                .at(input, line));
+            }
+            data.extend(cells);
+            height += 1;
+        }
+        Ok(Grid {
+            width,
+            height,
+            data,
+        })
+    }
+
     pub fn contains(&self, point: &Vec2) -> bool {
         point.x >= 0 && point.x < self.width as i32 && point.y >= 0 && point.y < self.height as i32
     }
 
+    /// Returns the cell at `point`, or `None` outside the grid.
+    pub fn get(&self, point: Vec2) -> Option<&T> {
+        self.contains(&point)
+            .then(|| &self.data[point.y as usize * self.width + point.x as usize])
+    }
+
+    pub fn get_mut(&mut self, point: Vec2) -> Option<&mut T> {
+        self.contains(&point)
+            .then(|| &mut self.data[point.y as usize * self.width + point.x as usize])
+    }
+
+    /// Reads the grid as if infinitely tiled.
+    pub fn wrapping(&self) -> View<'_, T> {
+        View::new(self, Outside::Wrap)
+    }
+
+    /// Reads points outside the grid from the nearest cell on its edge.
+    pub fn clamped(&self) -> View<'_, T> {
+        View::new(self, Outside::Clamp)
+    }
+
+    /// Reads `value` at every point outside the grid.
+    pub fn with_default(&self, value: T) -> View<'_, T> {
+        View::new(self, Outside::Default(value))
+    }
+
     pub fn iter_positions(&self) -> impl Iterator<Item = Vec2> + use<'_, T> {
         (0..self.height).flat_map(move |y| {
             (0..self.width).map(move |x| Vec2 {
@@ -56,6 +196,24 @@ impl<T> Grid<T> {
     }
 }
 
+impl<T: Tile> FromStr for Grid<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Grid::parse_with(s, |c, _| {
+            T::from_char(c).ok_or_else(|| Error::new(format!("unknown tile {:?}", c)))
+        })
+    }
+}
+
+impl FromStr for Grid<u8> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Grid::parse_bytes_with(s, |b, _| Ok(b))
+    }
+}
+
 impl<T> Index<Vec2> for Grid<T> {
     type Output = T;
 
@@ -71,3 +229,105 @@ impl<T> IndexMut<Vec2> for Grid<T> {
         &mut self.data[index.y as usize * self.width + index.x as usize]
     }
 }
+
+/// Returns a grid of `width` x `height` letters from `a` in reading order, such as
+/// `abc` over `def`, for the tests of the grid modules.
+#[cfg(test)]
+fn letters(width: usize, height: usize) -> Grid<char> {
+    Grid::from_fn(width, height, |p| {
+        (b'a' + (p.y as usize * width + p.x as usize) as u8) as char
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let grid: Grid<char> = "#..\n.#.\n".parse().unwrap();
+        assert_eq!((grid.width, grid.height), (3, 2));
+        assert_eq!(grid[Vec2::new(1, 1)], '#');
+        assert_eq!(grid.to_string(), "#..\n.#.\n");
+
+        let grid: Grid<u8> = "ab\r\ncd\n\n".parse().unwrap();
+        assert_eq!((grid.width, grid.height), (2, 2));
+        assert_eq!(grid[Vec2::new(0, 1)], b'c');
+
+        let grid: Grid<char> = "".parse().unwrap();
+        assert_eq!((grid.width, grid.height), (0, 0));
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        let error = "#..\n.#\n...".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(
+            error.reason,
+            "row of 2 cells, expected 3 as in the first row"
+        );
+        assert_eq!((error.line, error.column), (Some(2), Some(1)));
+        assert!("ab\n\ncd".parse::<Grid<u8>>().is_err());
+    }
+
+    #[test]
+    fn test_parse_with() {
+        let grid = Grid::parse_with("12\n34", |c, pos| {
+            let digit = c.to_digit(10).unwrap() as i32;
+            Ok(digit * 10 + pos.x)
+        })
+        .unwrap();
+        assert_eq!(grid[Vec2::new(1, 1)], 41);
+
+        let error = Grid::parse_with("..\n.é#", |c, _| match c {
+            '.' | 'é' => Ok(false),
+            _ => Err(Error::new(format!("unknown tile {:?}", c))),
+        })
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(3)));
+
+        let error = Grid::parse_bytes_with("..\n.x", |b, _| match b {
+            b'.' => Ok(()),
+            _ => Err(Error::new("unknown tile").on_line(7)),
+        })
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (Some(7), None));
+
+        // the second byte of 'é' is not a character boundary
+        let error = Grid::parse_bytes_with("..\n.é", |b, _| match b {
+            b'.' | 0xc3 => Ok(()),
+            _ => Err(Error::new("unknown tile")),
+        })
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid: Grid<char> = "ab\ncd".parse().unwrap();
+        assert_eq!(grid.get(Vec2::new(1, 0)), Some(&'b'));
+        assert_eq!(grid.get(Vec2::new(2, 0)), None);
+        assert_eq!(grid.get(Vec2::new(0, -1)), None);
+        *grid.get_mut(Vec2::new(0, 1)).unwrap() = 'x';
+        assert_eq!(grid[Vec2::new(0, 1)], 'x');
+        assert!(grid.get_mut(Vec2::new(-1, 0)).is_none());
+    }
+
+    #[test]
+    fn test_try_from_matrix() {
+        let grid = Grid::try_from_matrix(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!((grid.width, grid.height), (2, 2));
+        assert_eq!(grid[Vec2::new(0, 1)], 3);
+        let error = Grid::try_from_matrix(vec![vec![1, 2], vec![3, 4], vec![5]]).unwrap_err();
+        assert_eq!(
+            error.reason,
+            "row of 1 cells, expected 2 as in the first row"
+        );
+        assert_eq!(error.line, Some(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Rows of different lengths")]
+    fn test_from_matrix_ragged() {
+        Grid::from_matrix(vec![vec![1, 2], vec![3]]);
+    }
+}
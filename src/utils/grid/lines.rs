@@ -0,0 +1,144 @@
+use std::iter::FusedIterator;
+
+use super::Grid;
+use crate::utils::point::{Vec2, DOWN, RIGHT};
+
+/// Cells met walking from a point in a fixed direction until leaving the grid,
+/// the starting point included.
+#[derive(Debug, Clone)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    next: Vec2,
+    direction: Vec2,
+}
+
+impl<'a, T> Ray<'a, T> {
+    /// Yields the positions only.
+    pub fn positions(self) -> impl Iterator<Item = Vec2> + 'a {
+        self.map(|(p, _)| p)
+    }
+
+    /// Yields the cells only.
+    pub fn values(self) -> impl Iterator<Item = &'a T> + 'a {
+        self.map(|(_, v)| v)
+    }
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (Vec2, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.next;
+        let value = self.grid.get(point)?;
+        self.next = point + self.direction;
+        Some((point, value))
+    }
+}
+
+impl<T> FusedIterator for Ray<'_, T> {}
+
+impl<T> Grid<T> {
+    /// Walks from `from` towards `direction`, which must not be zero.
+    pub fn ray(&self, from: Vec2, direction: Vec2) -> Ray<'_, T> {
+        assert_ne!(direction, Vec2::default(), "Ray without direction");
+        Ray {
+            grid: self,
+            next: from,
+            direction,
+        }
+    }
+
+    /// Returns the cells of row `y`.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns the rows from top to bottom, each from left to right.
+    pub fn rows(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        (0..self.height).map(|y| self.ray(Vec2::new(0, y as i32), RIGHT))
+    }
+
+    /// Returns the columns from left to right, each from top to bottom.
+    pub fn columns(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        (0..self.width).map(|x| self.ray(Vec2::new(x as i32, 0), DOWN))
+    }
+
+    /// Returns the diagonals going down and right, from the bottom-left corner to
+    /// the top-right one.
+    pub fn diagonals(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        let starts = (0..self.height)
+            .rev()
+            .map(|y| Vec2::new(0, y as i32))
+            .chain((1..self.width).map(|x| Vec2::new(x as i32, 0)));
+        starts.map(|start| self.ray(start, Vec2::new(1, 1)))
+    }
+
+    /// Returns the diagonals going down and left, from the top-left corner to the
+    /// bottom-right one.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Ray<'_, T>> {
+        let right = self.width as i32 - 1;
+        let starts = (0..self.width)
+            .map(|x| Vec2::new(x as i32, 0))
+            .chain((1..self.height).map(move |y| Vec2::new(right, y as i32)));
+        starts.map(|start| self.ray(start, Vec2::new(-1, 1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{
+        grid::letters,
+        point::{LEFT, UP},
+    };
+
+    fn text<'a>(lines: impl Iterator<Item = Ray<'a, char>>) -> Vec<String> {
+        lines.map(|line| line.values().collect()).collect()
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = letters(3, 2);
+        let ray = grid.ray(Vec2::new(2, 1), LEFT).collect::<Vec<_>>();
+        assert_eq!(
+            ray,
+            [
+                (Vec2::new(2, 1), &'f'),
+                (Vec2::new(1, 1), &'e'),
+                (Vec2::new(0, 1), &'d')
+            ]
+        );
+        let ray = grid.ray(Vec2::new(0, 0), Vec2::new(2, 1)).positions();
+        assert_eq!(ray.collect::<Vec<_>>(), [Vec2::new(0, 0), Vec2::new(2, 1)]);
+        assert_eq!(grid.ray(Vec2::new(0, 0), UP).count(), 1);
+        assert_eq!(grid.ray(Vec2::new(0, 5), DOWN).count(), 0);
+    }
+
+    #[test]
+    fn test_scan_until_obstacle() {
+        let grid: Grid<char> = "..#.".parse().unwrap();
+        let free = grid
+            .ray(Vec2::new(0, 0), RIGHT)
+            .take_while(|&(_, &c)| c != '#')
+            .last();
+        assert_eq!(free, Some((Vec2::new(1, 0), &'.')));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = letters(3, 2);
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(text(grid.rows()), ["abc", "def"]);
+        assert_eq!(text(grid.columns()), ["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_diagonals() {
+        let grid = letters(3, 2);
+        assert_eq!(text(grid.diagonals()), ["d", "ae", "bf", "c"]);
+        assert_eq!(text(grid.anti_diagonals()), ["a", "bd", "ce", "f"]);
+        let empty: Grid<char> = "".parse().unwrap();
+        assert_eq!(empty.diagonals().count(), 0);
+        assert_eq!(empty.anti_diagonals().count(), 0);
+    }
+}
@@ -0,0 +1,96 @@
+use super::Grid;
+use crate::utils::point::{Vec2, DIRECTIONS4, DIRECTIONS8};
+
+/// Which points count as adjacent to a cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighborhood<'a> {
+    /// The four orthogonal neighbours.
+    VonNeumann,
+    /// The eight orthogonal and diagonal neighbours.
+    Moore,
+    /// The points at the given offsets, e.g. knight moves.
+    Custom(&'a [Vec2]),
+}
+
+impl<'a> Neighborhood<'a> {
+    pub fn offsets(&self) -> &'a [Vec2] {
+        match self {
+            Neighborhood::VonNeumann => &DIRECTIONS4,
+            Neighborhood::Moore => &DIRECTIONS8,
+            Neighborhood::Custom(offsets) => offsets,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns the neighbours of `point` inside the grid, in the order of the offsets.
+    pub fn neighbors<'a>(
+        &'a self,
+        point: Vec2,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = Vec2> + 'a {
+        neighborhood
+            .offsets()
+            .iter()
+            .map(move |&offset| point + offset)
+            .filter(|p| self.contains(p))
+    }
+
+    /// Like [`Grid::neighbors`], along with their cells.
+    pub fn neighbor_cells<'a>(
+        &'a self,
+        point: Vec2,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = (Vec2, &'a T)> + 'a {
+        self.neighbors(point, neighborhood).map(|p| (p, &self[p]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::letters;
+
+    #[test]
+    fn test_von_neumann() {
+        let grid = letters(3, 3);
+        let cells = |p| {
+            grid.neighbor_cells(p, Neighborhood::VonNeumann)
+                .map(|(_, &c)| c)
+                .collect::<String>()
+        };
+        assert_eq!(cells(Vec2::new(1, 1)), "bdfh");
+        assert_eq!(cells(Vec2::new(0, 0)), "bd");
+        assert_eq!(cells(Vec2::new(2, 2)), "fh");
+    }
+
+    #[test]
+    fn test_moore() {
+        let grid = letters(3, 3);
+        assert_eq!(
+            grid.neighbors(Vec2::new(1, 1), Neighborhood::Moore).count(),
+            8
+        );
+        let corner = grid
+            .neighbors(Vec2::new(0, 2), Neighborhood::Moore)
+            .collect::<Vec<_>>();
+        assert_eq!(corner, [(0, 1), (1, 1), (1, 2)].map(Vec2::from));
+        // points outside the grid still have neighbours inside it
+        assert_eq!(
+            grid.neighbors(Vec2::new(-1, -1), Neighborhood::Moore)
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_custom() {
+        let grid = letters(3, 3);
+        let knight = [(1, 2), (2, 1), (-1, 2), (-2, 1)].map(Vec2::from);
+        let cells = grid
+            .neighbor_cells(Vec2::new(0, 0), Neighborhood::Custom(&knight))
+            .map(|(_, &c)| c)
+            .collect::<String>();
+        assert_eq!(cells, "hf");
+    }
+}
@@ -0,0 +1,234 @@
+use super::{Grid, Neighborhood};
+use crate::utils::{
+    point::Vec2,
+    search::{self, Search},
+};
+
+/// Shortest paths from a start cell, found by [`Grid::bfs`], [`Grid::dijkstra`] or
+/// [`Grid::a_star`].
+#[derive(Debug, Clone)]
+pub struct Paths {
+    pub start: Vec2,
+    /// Cost of the cheapest path found to each reached cell. After [`Grid::a_star`],
+    /// only the goal and the cells of its path are sure to be the cheapest.
+    pub distances: Grid<Option<u64>>,
+    /// Cell coming before each reached cell on that path, `None` for the start.
+    pub predecessors: Grid<Option<Vec2>>,
+}
+
+impl Paths {
+    fn new(start: Vec2, width: usize, height: usize, search: &Search<Vec2>) -> Self {
+        let mut distances = Grid::from_fn(width, height, |_| None);
+        let mut predecessors = Grid::from_fn(width, height, |_| None);
+        for (&point, distance) in search.reached() {
+            distances[point] = Some(distance);
+            predecessors[point] = search.predecessor(&point).copied();
+        }
+        Paths {
+            start,
+            distances,
+            predecessors,
+        }
+    }
+
+    pub fn distance(&self, to: Vec2) -> Option<u64> {
+        self.distances.get(to).copied().flatten()
+    }
+
+    /// Returns the cells of the path from the start to `to`, both included, if
+    /// `to` was reached.
+    pub fn path(&self, to: Vec2) -> Option<Vec<Vec2>> {
+        self.distance(to)?;
+        let mut path = vec![to];
+        let mut point = to;
+        while let Some(previous) = self.predecessors[point] {
+            path.push(previous);
+            point = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Explores from `start`, stepping from a cell to a neighbour when
+    /// `passable(cell, neighbour)` holds, each step costing 1.
+    ///
+    /// The start is reached whatever its cell.
+    pub fn bfs<P>(&self, start: Vec2, neighborhood: Neighborhood, mut passable: P) -> Paths
+    where
+        P: FnMut(&T, &T) -> bool,
+    {
+        assert!(self.contains(&start), "Start not in grid");
+        let search = search::bfs(
+            start,
+            |&point| self.steps(point, neighborhood, &mut passable),
+            |_| false,
+        );
+        Paths::new(start, self.width, self.height, &search)
+    }
+
+    /// Explores from `start` like [`Grid::bfs`], a step from a cell to a neighbour
+    /// costing `cost(cell, neighbour)`.
+    pub fn dijkstra<P, C>(
+        &self,
+        start: Vec2,
+        neighborhood: Neighborhood,
+        mut passable: P,
+        mut cost: C,
+    ) -> Paths
+    where
+        P: FnMut(&T, &T) -> bool,
+        C: FnMut(&T, &T) -> u64,
+    {
+        assert!(self.contains(&start), "Start not in grid");
+        let search = search::dijkstra(
+            start,
+            |&point| self.costed_steps(point, neighborhood, &mut passable, &mut cost),
+            |_| false,
+        );
+        Paths::new(start, self.width, self.height, &search)
+    }
+
+    /// Like [`Grid::dijkstra`], stopping once the cheapest path to `goal` is known
+    /// and exploring first towards it.
+    ///
+    /// The Manhattan distance to the goal guides the search, so a step must cost at
+    /// least the Manhattan distance it covers for the path found to be the cheapest.
+    pub fn a_star<P, C>(
+        &self,
+        start: Vec2,
+        goal: Vec2,
+        neighborhood: Neighborhood,
+        mut passable: P,
+        mut cost: C,
+    ) -> Paths
+    where
+        P: FnMut(&T, &T) -> bool,
+        C: FnMut(&T, &T) -> u64,
+    {
+        assert!(self.contains(&start), "Start not in grid");
+        let search = search::a_star(
+            start,
+            |&point| self.costed_steps(point, neighborhood, &mut passable, &mut cost),
+            |&point| point == goal,
+            |point| point.manhattan(&goal) as u64,
+        );
+        Paths::new(start, self.width, self.height, &search)
+    }
+
+    /// Returns the neighbours of `point` that `passable` lets it step to.
+    fn steps<P>(&self, point: Vec2, neighborhood: Neighborhood, passable: &mut P) -> Vec<Vec2>
+    where
+        P: FnMut(&T, &T) -> bool,
+    {
+        self.neighbors(point, neighborhood)
+            .filter(|&next| passable(&self[point], &self[next]))
+            .collect()
+    }
+
+    /// Like [`Grid::steps`], along with the cost of each step.
+    fn costed_steps<P, C>(
+        &self,
+        point: Vec2,
+        neighborhood: Neighborhood,
+        passable: &mut P,
+        cost: &mut C,
+    ) -> Vec<(Vec2, u64)>
+    where
+        P: FnMut(&T, &T) -> bool,
+        C: FnMut(&T, &T) -> u64,
+    {
+        self.steps(point, neighborhood, passable)
+            .into_iter()
+            .map(|next| (next, cost(&self[point], &self[next])))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "\
+S.#...
+.##.#.
+...#..
+.#...E";
+
+    fn maze() -> (Grid<char>, Vec2, Vec2) {
+        (MAZE.parse().unwrap(), Vec2::new(0, 0), Vec2::new(5, 3))
+    }
+
+    fn open(_: &char, c: &char) -> bool {
+        *c != '#'
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (grid, start, end) = maze();
+        let paths = grid.bfs(start, Neighborhood::VonNeumann, open);
+        assert_eq!(paths.distance(end), Some(8));
+        let path = paths.path(end).unwrap();
+        assert_eq!(path.len(), 9);
+        assert_eq!((path[0], path[8]), (start, end));
+        assert!(path.windows(2).all(|w| w[0].manhattan(&w[1]) == 1));
+        assert!(path.iter().all(|&p| grid[p] != '#'));
+        assert_eq!(paths.distance(Vec2::new(3, 0)), Some(13));
+        assert_eq!(paths.path(start), Some(vec![start]));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let grid: Grid<char> = "S#.\n##.".parse().unwrap();
+        let paths = grid.bfs(Vec2::new(0, 0), Neighborhood::VonNeumann, open);
+        assert_eq!(paths.distance(Vec2::new(2, 1)), None);
+        assert_eq!(paths.path(Vec2::new(2, 1)), None);
+        assert_eq!(paths.path(Vec2::new(7, 7)), None);
+    }
+
+    #[test]
+    fn test_dijkstra_costs() {
+        // entering a digit costs its value, the long way round is cheaper
+        let grid: Grid<char> = "S9E\n111".parse().unwrap();
+        let cost = |_: &char, c: &char| c.to_digit(10).unwrap_or(1) as u64;
+        let paths = grid.dijkstra(Vec2::new(0, 0), Neighborhood::VonNeumann, open, cost);
+        assert_eq!(paths.distance(Vec2::new(2, 0)), Some(4));
+        assert_eq!(
+            paths.path(Vec2::new(2, 0)).unwrap(),
+            [(0, 0), (0, 1), (1, 1), (2, 1), (2, 0)].map(Vec2::from)
+        );
+    }
+
+    #[test]
+    fn test_a_star() {
+        let (grid, start, end) = maze();
+        let unit = |_: &char, _: &char| 1;
+        let paths = grid.a_star(start, end, Neighborhood::VonNeumann, open, unit);
+        let bfs = grid.bfs(start, Neighborhood::VonNeumann, open);
+        assert_eq!(paths.distance(end), bfs.distance(end));
+        assert_eq!(paths.path(end).unwrap().len(), 9);
+        // the dead end in the top-right corner is never explored
+        assert_eq!(paths.distance(Vec2::new(3, 0)), None);
+    }
+
+    #[test]
+    fn test_climb() {
+        // a step may climb at most one, so the wall of 9s is gone round
+        let grid: Grid<char> = "0123\n9994\n8765".parse().unwrap();
+        let climb = |from: &char, to: &char| *to as u8 <= *from as u8 + 1;
+        let paths = grid.bfs(Vec2::new(0, 0), Neighborhood::VonNeumann, climb);
+        assert_eq!(paths.distance(Vec2::new(0, 2)), Some(8));
+        assert_eq!(paths.distance(Vec2::new(0, 1)), Some(9));
+    }
+
+    #[test]
+    fn test_huge_costs() {
+        // stepping past the wall would cost more than u64::MAX
+        let grid: Grid<char> = "S#E".parse().unwrap();
+        let cost = |_: &char, c: &char| if *c == '#' { u64::MAX } else { 1 };
+        let paths = grid.dijkstra(Vec2::new(0, 0), Neighborhood::VonNeumann, |_, _| true, cost);
+        assert_eq!(paths.distance(Vec2::new(1, 0)), Some(u64::MAX));
+        assert_eq!(paths.distance(Vec2::new(2, 0)), None);
+    }
+}
@@ -0,0 +1,128 @@
+use super::Grid;
+use crate::utils::point::Vec2;
+
+/// Orientations of a pattern searched by [`Grid::find_pattern_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symmetry {
+    /// The pattern as given.
+    None,
+    /// The four quarter-turn rotations.
+    Rotations,
+    /// The rotations and their mirror images.
+    RotationsAndReflections,
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Returns the top-left corners of the places where `pattern` matches, `None`
+    /// cells matching anything, in reading order.
+    pub fn find_pattern(&self, pattern: &Grid<Option<T>>) -> Vec<Vec2> {
+        if pattern.width > self.width || pattern.height > self.height {
+            return Vec::new();
+        }
+        let anchors = Grid::<()>::from_fn(
+            self.width - pattern.width + 1,
+            self.height - pattern.height + 1,
+            |_| (),
+        );
+        anchors
+            .iter_positions()
+            .filter(|&anchor| {
+                pattern.iter_positions().all(|p| match &pattern[p] {
+                    Some(expected) => self[anchor + p] == *expected,
+                    None => true,
+                })
+            })
+            .collect()
+    }
+}
+
+impl<T: PartialEq + Clone> Grid<T> {
+    /// Like [`Grid::find_pattern`], also trying the orientations of the pattern
+    /// allowed by `symmetry`.
+    ///
+    /// An anchor is the top-left corner of the oriented pattern, reported once for
+    /// each distinct orientation matching there.
+    pub fn find_pattern_with(&self, pattern: &Grid<Option<T>>, symmetry: Symmetry) -> Vec<Vec2> {
+        let mut orientations = vec![pattern.clone()];
+        if symmetry != Symmetry::None {
+            orientations.extend([
+                pattern.rotate_90(),
+                pattern.rotate_180(),
+                pattern.rotate_270(),
+            ]);
+        }
+        if symmetry == Symmetry::RotationsAndReflections {
+            let mirrored = orientations
+                .iter()
+                .map(Grid::flip_horizontal)
+                .collect::<Vec<_>>();
+            orientations.extend(mirrored);
+        }
+
+        let mut distinct: Vec<Grid<Option<T>>> = Vec::new();
+        for orientation in orientations {
+            let known = distinct.iter().any(|d| {
+                (d.width, d.height) == (orientation.width, orientation.height)
+                    && d.data == orientation.data
+            });
+            if !known {
+                distinct.push(orientation);
+            }
+        }
+
+        let mut anchors = distinct
+            .iter()
+            .flat_map(|orientation| self.find_pattern(orientation))
+            .collect::<Vec<_>>();
+        anchors.sort_by_key(|p| (p.y, p.x));
+        anchors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(text: &str) -> Grid<Option<char>> {
+        Grid::parse_with(text, |c, _| Ok((c != '?').then_some(c))).unwrap()
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let grid: Grid<char> = "abab\nbaba\nabab".parse().unwrap();
+        let anchors = grid.find_pattern(&pattern("ab\n?a"));
+        assert_eq!(anchors, [(0, 0), (2, 0), (1, 1)].map(Vec2::from));
+        assert!(grid.find_pattern(&pattern("aa")).is_empty());
+        assert!(grid.find_pattern(&pattern("?????")).is_empty());
+        assert_eq!(grid.find_pattern(&pattern("?")).len(), 12);
+    }
+
+    #[test]
+    fn test_rotations() {
+        let grid: Grid<char> = "M.S.\n.A..\nM.S.\n....".parse().unwrap();
+        let x_mas = pattern("M?M\n?A?\nS?S");
+        assert!(grid.find_pattern(&x_mas).is_empty());
+        assert_eq!(
+            grid.find_pattern_with(&x_mas, Symmetry::Rotations),
+            [Vec2::new(0, 0)]
+        );
+        // mirror images of this pattern are rotations, reported once
+        assert_eq!(
+            grid.find_pattern_with(&x_mas, Symmetry::RotationsAndReflections),
+            [Vec2::new(0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_reflections() {
+        let grid: Grid<char> = "ab.\n.c.".parse().unwrap();
+        let hook = pattern("ba\nc?");
+        assert!(grid
+            .find_pattern_with(&hook, Symmetry::Rotations)
+            .is_empty());
+        assert_eq!(
+            grid.find_pattern_with(&hook, Symmetry::RotationsAndReflections),
+            [Vec2::new(0, 0)]
+        );
+    }
+}
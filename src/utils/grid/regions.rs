@@ -0,0 +1,223 @@
+use std::{collections::VecDeque, fmt};
+
+use super::{Grid, Neighborhood};
+use crate::utils::point::{Vec2, DOWN, LEFT, RIGHT, UP};
+
+/// Index of a region in [`Regions::regions`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RegionId(pub usize);
+
+impl fmt::Display for RegionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A connected set of cells and its measures.
+///
+/// Perimeter and sides follow the edges between the region and the cells around it,
+/// or the outside of the grid, whatever the connectivity used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub id: RegionId,
+    /// Cells in the order they were reached from the first one.
+    pub cells: Vec<Vec2>,
+    pub perimeter: usize,
+    /// Straight sides of the outline, which equal its corners.
+    pub sides: usize,
+    /// Top-left corner of the bounding box.
+    pub min: Vec2,
+    /// Bottom-right corner of the bounding box.
+    pub max: Vec2,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+/// Every cell of a grid labelled with its region.
+#[derive(Debug, Clone)]
+pub struct Regions {
+    pub labels: Grid<RegionId>,
+    pub regions: Vec<Region>,
+}
+
+impl Regions {
+    pub fn region_of(&self, point: Vec2) -> &Region {
+        &self.regions[self.labels[point].0]
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns the cells reachable from `seed` moving to neighbours for which
+    /// `same(cell, neighbour)` holds, in breadth-first order.
+    pub fn flood_fill<F>(&self, seed: Vec2, neighborhood: Neighborhood, mut same: F) -> Vec<Vec2>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut seen = Grid::from_fn(self.width, self.height, |_| false);
+        self.fill(seed, neighborhood, &mut same, &mut seen)
+    }
+
+    fn fill<F>(
+        &self,
+        seed: Vec2,
+        neighborhood: Neighborhood,
+        same: &mut F,
+        seen: &mut Grid<bool>,
+    ) -> Vec<Vec2>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut cells = Vec::new();
+        if seen.get(seed) != Some(&false) {
+            return cells;
+        }
+        seen[seed] = true;
+        let mut queue = VecDeque::from([seed]);
+        while let Some(point) = queue.pop_front() {
+            cells.push(point);
+            for next in self.neighbors(point, neighborhood) {
+                if !seen[next] && same(&self[point], &self[next]) {
+                    seen[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        cells
+    }
+
+    /// Splits the grid into connected regions, see [`Grid::flood_fill`].
+    ///
+    /// Regions are numbered in the reading order of their first cell.
+    pub fn regions<F>(&self, neighborhood: Neighborhood, mut same: F) -> Regions
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut seen = Grid::from_fn(self.width, self.height, |_| false);
+        let mut labels = Grid::from_fn(self.width, self.height, |_| RegionId::default());
+        let mut regions = Vec::new();
+        for point in self.iter_positions() {
+            if seen[point] {
+                continue;
+            }
+            let id = RegionId(regions.len());
+            let cells = self.fill(point, neighborhood, &mut same, &mut seen);
+            for &cell in &cells {
+                labels[cell] = id;
+            }
+            regions.push(cells);
+        }
+
+        let regions = regions
+            .into_iter()
+            .enumerate()
+            .map(|(id, cells)| measure(&labels, RegionId(id), cells))
+            .collect();
+        Regions { labels, regions }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Splits the grid into connected regions of equal cells.
+    pub fn components(&self, neighborhood: Neighborhood) -> Regions {
+        self.regions(neighborhood, |a, b| a == b)
+    }
+}
+
+fn measure(labels: &Grid<RegionId>, id: RegionId, cells: Vec<Vec2>) -> Region {
+    let inside = |p: Vec2| labels.get(p) == Some(&id);
+    let (mut perimeter, mut sides) = (0, 0);
+    let (mut min, mut max) = (cells[0], cells[0]);
+    for &cell in &cells {
+        perimeter += cell
+            .neighbors4()
+            .into_iter()
+            .filter(|&n| !inside(n))
+            .count();
+        // each corner of the outline is a corner of one of its cells, convex when
+        // both sides around it are outside, concave when only the diagonal is
+        for (a, b) in [(UP, LEFT), (UP, RIGHT), (DOWN, LEFT), (DOWN, RIGHT)] {
+            let (side_a, side_b, diagonal) =
+                (inside(cell + a), inside(cell + b), inside(cell + a + b));
+            if (!side_a && !side_b) || (side_a && side_b && !diagonal) {
+                sides += 1;
+            }
+        }
+        min = Vec2::new(min.x.min(cell.x), min.y.min(cell.y));
+        max = Vec2::new(max.x.max(cell.x), max.y.max(cell.y));
+    }
+    Region {
+        id,
+        cells,
+        perimeter,
+        sides,
+        min,
+        max,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GARDEN: &str = "AAAA\nBBCD\nBBCC\nEEEC";
+
+    #[test]
+    fn test_flood_fill() {
+        let grid: Grid<char> = GARDEN.parse().unwrap();
+        let c = grid.flood_fill(Vec2::new(2, 1), Neighborhood::VonNeumann, |a, b| a == b);
+        assert_eq!(c, [(2, 1), (2, 2), (3, 2), (3, 3)].map(Vec2::from));
+        let all = grid.flood_fill(Vec2::new(0, 0), Neighborhood::Moore, |_, _| true);
+        assert_eq!(all.len(), 16);
+        assert!(grid
+            .flood_fill(Vec2::new(4, 0), Neighborhood::Moore, |_, _| true)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_components() {
+        let grid: Grid<char> = GARDEN.parse().unwrap();
+        let regions = grid.components(Neighborhood::VonNeumann);
+        let measures = regions
+            .regions
+            .iter()
+            .map(|r| (grid[r.cells[0]], r.area(), r.perimeter, r.sides))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            measures,
+            [
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4)
+            ]
+        );
+        assert_eq!(regions.labels[Vec2::new(3, 3)], RegionId(2));
+        let c = regions.region_of(Vec2::new(3, 3));
+        assert_eq!((c.min, c.max), (Vec2::new(2, 1), Vec2::new(3, 3)));
+    }
+
+    #[test]
+    fn test_holes() {
+        // the outer region surrounds two single cells
+        let grid: Grid<char> = "OOOOO\nOXOXO\nOOOOO".parse().unwrap();
+        let regions = grid.components(Neighborhood::VonNeumann);
+        assert_eq!(regions.regions.len(), 3);
+        let outer = &regions.regions[0];
+        assert_eq!((outer.area(), outer.perimeter, outer.sides), (13, 24, 12));
+    }
+
+    #[test]
+    fn test_moore_connectivity() {
+        let grid: Grid<char> = "X.\n.X".parse().unwrap();
+        assert_eq!(grid.components(Neighborhood::VonNeumann).regions.len(), 4);
+        let regions = grid.components(Neighborhood::Moore);
+        assert_eq!(regions.regions.len(), 2);
+        let x = &regions.regions[0];
+        assert_eq!((x.area(), x.perimeter, x.sides), (2, 8, 8));
+    }
+}
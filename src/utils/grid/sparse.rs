@@ -0,0 +1,232 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use super::{Grid, Neighborhood};
+use crate::utils::point::Vec2;
+
+/// Cells at any points, negative ones included, stored in a hash map.
+///
+/// The bounding box grows as cells are inserted and does not shrink when they
+/// are removed.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vec2, T>,
+    bounds: Option<(Vec2, Vec2)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the top-left and bottom-right corners of the cells ever inserted.
+    pub fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        self.bounds
+    }
+
+    /// Returns the size of the bounding box.
+    pub fn size(&self) -> (usize, usize) {
+        self.bounds.map_or((0, 0), |(min, max)| {
+            ((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize)
+        })
+    }
+
+    /// Sets the cell at `point`, returning the one it replaces.
+    pub fn insert(&mut self, point: Vec2, value: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (
+                Vec2::new(min.x.min(point.x), min.y.min(point.y)),
+                Vec2::new(max.x.max(point.x), max.y.max(point.y)),
+            ),
+            None => (point, point),
+        });
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Vec2) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    /// Returns whether a cell is set at `point`.
+    pub fn contains(&self, point: &Vec2) -> bool {
+        self.cells.contains_key(point)
+    }
+
+    pub fn get(&self, point: Vec2) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    pub fn get_mut(&mut self, point: Vec2) -> Option<&mut T> {
+        self.cells.get_mut(&point)
+    }
+
+    /// Returns the cells in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2, &T)> {
+        let mut cells = self.cells.iter().map(|(&p, v)| (p, v)).collect::<Vec<_>>();
+        cells.sort_by_key(|(p, _)| (p.y, p.x));
+        cells.into_iter()
+    }
+
+    /// Returns the neighbours of `point` holding a cell, in the order of the offsets.
+    pub fn neighbors<'a>(
+        &'a self,
+        point: Vec2,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = Vec2> + 'a {
+        neighborhood
+            .offsets()
+            .iter()
+            .map(move |&offset| point + offset)
+            .filter(|p| self.contains(p))
+    }
+
+    /// Like [`SparseGrid::neighbors`], along with their cells.
+    pub fn neighbor_cells<'a>(
+        &'a self,
+        point: Vec2,
+        neighborhood: Neighborhood<'a>,
+    ) -> impl Iterator<Item = (Vec2, &'a T)> + 'a {
+        self.neighbors(point, neighborhood).map(|p| (p, &self[p]))
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Copies the bounding box into a grid, the top-left corner moving to `(0, 0)`
+    /// and `missing` filling the points without a cell.
+    pub fn to_grid(&self, missing: T) -> Grid<T> {
+        let (width, height) = self.size();
+        let origin = self.bounds.map_or_else(Vec2::default, |(min, _)| min);
+        Grid::from_fn(width, height, |p| {
+            self.get(origin + p).unwrap_or(&missing).clone()
+        })
+    }
+}
+
+impl<T> From<Grid<T>> for SparseGrid<T> {
+    fn from(grid: Grid<T>) -> Self {
+        let positions = grid.iter_positions().collect::<Vec<_>>();
+        positions.into_iter().zip(grid.data).collect()
+    }
+}
+
+impl<T> FromIterator<(Vec2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Vec2, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Vec2, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Vec2, T)>>(&mut self, iter: I) {
+        for (point, value) in iter {
+            self.insert(point, value);
+        }
+    }
+}
+
+impl<T> Index<Vec2> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, index: Vec2) -> &Self::Output {
+        self.get(index).expect("No cell at point")
+    }
+}
+
+impl<T> IndexMut<Vec2> for SparseGrid<T> {
+    fn index_mut(&mut self, index: Vec2) -> &mut Self::Output {
+        self.get_mut(index).expect("No cell at point")
+    }
+}
+
+/// Draws the bounding box, with `.` at the points without a cell.
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                match self.get(Vec2::new(x, y)) {
+                    Some(cell) => write!(f, "{}", cell)?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rocks() -> SparseGrid<char> {
+        [(-2, -1), (1, 0), (0, 2)]
+            .map(Vec2::from)
+            .into_iter()
+            .map(|p| (p, '#'))
+            .collect()
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = rocks();
+        assert_eq!(grid.bounds(), Some((Vec2::new(-2, -1), Vec2::new(1, 2))));
+        assert_eq!(grid.size(), (4, 4));
+        assert_eq!(grid.remove(Vec2::new(1, 0)), Some('#'));
+        assert_eq!(grid.size(), (4, 4));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(SparseGrid::<char>::new().size(), (0, 0));
+    }
+
+    #[test]
+    fn test_cells() {
+        let mut grid = rocks();
+        assert_eq!(grid[Vec2::new(-2, -1)], '#');
+        assert_eq!(grid.get(Vec2::new(0, 0)), None);
+        grid[Vec2::new(0, 2)] = 'o';
+        assert_eq!(grid.insert(Vec2::new(0, 2), '~'), Some('o'));
+        let cells = grid.iter().map(|(_, &c)| c).collect::<String>();
+        assert_eq!(cells, "##~");
+        let around = grid.neighbors(Vec2::new(0, 1), Neighborhood::Moore);
+        assert_eq!(around.collect::<Vec<_>>(), [(1, 0), (0, 2)].map(Vec2::from));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(rocks().to_string(), "#...\n...#\n....\n..#.\n");
+        assert_eq!(SparseGrid::<char>::new().to_string(), "");
+    }
+
+    #[test]
+    fn test_grid_conversion() {
+        let dense = rocks().to_grid('.');
+        assert_eq!(dense.to_string(), rocks().to_string());
+        let sparse = SparseGrid::from(dense);
+        assert_eq!(sparse.len(), 16);
+        assert_eq!(sparse[Vec2::new(3, 1)], '#');
+        assert_eq!(sparse.to_grid(' ').to_string(), rocks().to_string());
+    }
+}
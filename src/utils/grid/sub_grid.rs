@@ -0,0 +1,116 @@
+use std::{fmt, ops::Index};
+
+use super::Grid;
+use crate::utils::point::Vec2;
+
+/// A borrowed rectangle of a grid, with `(0, 0)` at its top-left corner.
+#[derive(Debug, Clone, Copy)]
+pub struct SubGrid<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Vec2,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<'a, T> SubGrid<'a, T> {
+    /// Returns the position in the whole grid of a point of the sub-grid.
+    pub fn to_parent(&self, point: Vec2) -> Vec2 {
+        self.origin + point
+    }
+
+    pub fn contains(&self, point: &Vec2) -> bool {
+        point.x >= 0 && point.x < self.width as i32 && point.y >= 0 && point.y < self.height as i32
+    }
+
+    pub fn get(&self, point: Vec2) -> Option<&'a T> {
+        let grid = self.grid;
+        self.contains(&point).then(|| &grid[self.to_parent(point)])
+    }
+
+    pub fn iter_positions(&self) -> impl Iterator<Item = Vec2> + use<'_, T> {
+        (0..self.height)
+            .flat_map(move |y| (0..self.width).map(move |x| Vec2::new(x as i32, y as i32)))
+    }
+}
+
+impl<T: Clone> SubGrid<'_, T> {
+    /// Copies the cells into a grid of their own.
+    pub fn to_grid(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |p| self[p].clone())
+    }
+}
+
+impl<T> Index<Vec2> for SubGrid<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: Vec2) -> &Self::Output {
+        self.get(index).expect("Point not in sub-grid")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for SubGrid<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                write!(f, "{}", self[Vec2::new(x as i32, y as i32)])?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    /// Borrows the `width` x `height` rectangle whose top-left corner is `origin`,
+    /// which must fit in the grid.
+    pub fn sub_grid(&self, origin: Vec2, width: usize, height: usize) -> SubGrid<'_, T> {
+        assert!(
+            origin.x >= 0
+                && origin.y >= 0
+                && origin.x as usize + width <= self.width
+                && origin.y as usize + height <= self.height,
+            "Sub-grid not in grid"
+        );
+        SubGrid {
+            grid: self,
+            origin,
+            width,
+            height,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::letters;
+
+    #[test]
+    fn test_sub_grid() {
+        let grid = letters(4, 3);
+        let sub = grid.sub_grid(Vec2::new(1, 1), 2, 2);
+        assert_eq!(sub[Vec2::new(0, 0)], 'f');
+        assert_eq!(sub[Vec2::new(1, 1)], 'k');
+        assert_eq!(sub.get(Vec2::new(2, 0)), None);
+        assert_eq!(sub.to_parent(Vec2::new(1, 0)), Vec2::new(2, 1));
+        assert_eq!(sub.iter_positions().count(), 4);
+        assert_eq!(sub.to_string(), "fg\njk\n");
+        assert_eq!(sub.to_grid().rotate_180().to_string(), "kj\ngf\n");
+    }
+
+    #[test]
+    fn test_whole_grid() {
+        let grid = letters(4, 3);
+        assert_eq!(
+            grid.sub_grid(Vec2::new(0, 0), 4, 3).to_string(),
+            grid.to_string()
+        );
+        assert_eq!(grid.sub_grid(Vec2::new(4, 3), 0, 0).to_string(), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "Sub-grid not in grid")]
+    fn test_sub_grid_too_large() {
+        letters(4, 3).sub_grid(Vec2::new(2, 0), 3, 1);
+    }
+}
@@ -0,0 +1,171 @@
+use super::Grid;
+use crate::utils::point::Vec2;
+
+impl<T> Grid<T> {
+    /// Builds a grid calling `cell` on each position, in reading order.
+    pub fn from_fn(width: usize, height: usize, cell: impl FnMut(Vec2) -> T) -> Self {
+        let data = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Vec2::new(x as i32, y as i32)))
+            .map(cell)
+            .collect();
+        Grid::from_vec(width, height, data)
+    }
+
+    /// Inserts a row before row `y`, or at the bottom when `y` is the height.
+    pub fn insert_row(&mut self, y: usize, row: Vec<T>) {
+        assert!(y <= self.height, "Row out of the grid");
+        if self.height == 0 {
+            self.width = row.len();
+        }
+        assert_eq!(row.len(), self.width, "Row of the wrong length");
+        let at = y * self.width;
+        self.data.splice(at..at, row);
+        self.height += 1;
+    }
+
+    pub fn remove_row(&mut self, y: usize) -> Vec<T> {
+        assert!(y < self.height, "Row out of the grid");
+        self.height -= 1;
+        self.data
+            .drain(y * self.width..(y + 1) * self.width)
+            .collect()
+    }
+
+    /// Inserts a column before column `x`, or on the right when `x` is the width.
+    pub fn insert_column(&mut self, x: usize, column: Vec<T>) {
+        assert!(x <= self.width, "Column out of the grid");
+        if self.width == 0 {
+            self.height = column.len();
+        }
+        assert_eq!(column.len(), self.height, "Column of the wrong length");
+        let width = self.width;
+        for (y, cell) in column.into_iter().enumerate() {
+            self.data.insert(y * (width + 1) + x, cell);
+        }
+        self.width += 1;
+    }
+
+    pub fn remove_column(&mut self, x: usize) -> Vec<T> {
+        assert!(x < self.width, "Column out of the grid");
+        let column = (0..self.height)
+            .rev()
+            .map(|y| self.data.remove(y * self.width + x))
+            .collect::<Vec<_>>();
+        self.width -= 1;
+        column.into_iter().rev().collect()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Vec2::new(p.y, p.x)].clone()
+        })
+    }
+
+    /// Rotates a quarter turn clockwise, the left column becoming the top row.
+    pub fn rotate_90(&self) -> Self {
+        let bottom = self.height as i32 - 1;
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Vec2::new(p.y, bottom - p.x)].clone()
+        })
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        let (right, bottom) = (self.width as i32 - 1, self.height as i32 - 1);
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Vec2::new(right - p.x, bottom - p.y)].clone()
+        })
+    }
+
+    /// Rotates a quarter turn anti-clockwise, the top row becoming the left column.
+    pub fn rotate_270(&self) -> Self {
+        let right = self.width as i32 - 1;
+        Grid::from_fn(self.height, self.width, |p| {
+            self[Vec2::new(right - p.y, p.x)].clone()
+        })
+    }
+
+    /// Mirrors left and right.
+    pub fn flip_horizontal(&self) -> Self {
+        let right = self.width as i32 - 1;
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Vec2::new(right - p.x, p.y)].clone()
+        })
+    }
+
+    /// Mirrors top and bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let bottom = self.height as i32 - 1;
+        Grid::from_fn(self.width, self.height, |p| {
+            self[Vec2::new(p.x, bottom - p.y)].clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::letters;
+
+    #[test]
+    fn test_transpose() {
+        assert_eq!(letters(3, 2).transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(
+            letters(3, 2).transpose().transpose().to_string(),
+            "abc\ndef\n"
+        );
+    }
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(letters(3, 2).rotate_90().to_string(), "da\neb\nfc\n");
+        assert_eq!(letters(3, 2).rotate_180().to_string(), "fed\ncba\n");
+        assert_eq!(letters(3, 2).rotate_270().to_string(), "cf\nbe\nad\n");
+        let twice = letters(3, 2).rotate_90().rotate_90();
+        assert_eq!(twice.to_string(), letters(3, 2).rotate_180().to_string());
+    }
+
+    #[test]
+    fn test_flip() {
+        assert_eq!(letters(3, 2).flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(letters(3, 2).flip_vertical().to_string(), "def\nabc\n");
+    }
+
+    #[test]
+    fn test_rows() {
+        let mut grid = letters(3, 2);
+        grid.insert_row(1, vec!['x', 'y', 'z']);
+        grid.insert_row(3, vec!['1', '2', '3']);
+        assert_eq!(grid.to_string(), "abc\nxyz\ndef\n123\n");
+        assert_eq!(grid.remove_row(0), ['a', 'b', 'c']);
+        assert_eq!(grid.to_string(), "xyz\ndef\n123\n");
+        assert_eq!(grid.height, 3);
+    }
+
+    #[test]
+    fn test_columns() {
+        let mut grid = letters(3, 2);
+        grid.insert_column(0, vec!['x', 'y']);
+        grid.insert_column(4, vec!['1', '2']);
+        assert_eq!(grid.to_string(), "xabc1\nydef2\n");
+        assert_eq!(grid.remove_column(2), ['b', 'e']);
+        assert_eq!(grid.to_string(), "xac1\nydf2\n");
+        assert_eq!(grid.width, 4);
+    }
+
+    #[test]
+    fn test_insert_into_empty() {
+        let mut grid: Grid<char> = "".parse().unwrap();
+        grid.insert_column(0, vec!['a', 'b']);
+        grid.insert_row(2, vec!['c']);
+        assert_eq!(grid.to_string(), "a\nb\nc\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "Row of the wrong length")]
+    fn test_insert_ragged_row() {
+        letters(3, 2).insert_row(0, vec!['x']);
+    }
+}
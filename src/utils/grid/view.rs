@@ -0,0 +1,117 @@
+use std::ops::Index;
+
+use super::Grid;
+use crate::utils::point::Vec2;
+
+/// How a [`View`] reads points outside its grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outside<T> {
+    /// The grid repeats in every direction.
+    Wrap,
+    /// Points read the nearest cell on the edge.
+    Clamp,
+    /// Points read this value.
+    Default(T),
+}
+
+/// Read access to a grid that accepts any point, see [`Outside`].
+///
+/// Views borrow the grid, so they are cheap to create wherever needed.
+#[derive(Debug, Clone)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    outside: Outside<T>,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn new(grid: &'a Grid<T>, outside: Outside<T>) -> Self {
+        View { grid, outside }
+    }
+
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+
+    /// Returns the point of the grid read for `point`, `None` when it reads the
+    /// default value or the grid is empty.
+    pub fn position(&self, point: Vec2) -> Option<Vec2> {
+        let (width, height) = (self.grid.width as i32, self.grid.height as i32);
+        if self.grid.contains(&point) {
+            return Some(point);
+        }
+        if width == 0 || height == 0 {
+            return None;
+        }
+        match self.outside {
+            Outside::Wrap => Some(Vec2::new(
+                point.x.rem_euclid(width),
+                point.y.rem_euclid(height),
+            )),
+            Outside::Clamp => Some(Vec2::new(
+                point.x.clamp(0, width - 1),
+                point.y.clamp(0, height - 1),
+            )),
+            Outside::Default(_) => None,
+        }
+    }
+
+    /// Returns the cell read at `point`, `None` only for empty grids without default.
+    pub fn get(&self, point: Vec2) -> Option<&T> {
+        match (self.position(point), &self.outside) {
+            (Some(position), _) => Some(&self.grid[position]),
+            (None, Outside::Default(value)) => Some(value),
+            (None, _) => None,
+        }
+    }
+}
+
+impl<T> Index<Vec2> for View<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: Vec2) -> &Self::Output {
+        self.get(index).expect("Empty grid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::grid::letters;
+
+    #[test]
+    fn test_wrap() {
+        let grid = letters(3, 2);
+        let view = grid.wrapping();
+        assert_eq!(view[Vec2::new(1, 1)], 'e');
+        assert_eq!(view[Vec2::new(3, 0)], 'a');
+        assert_eq!(view[Vec2::new(-1, -1)], 'f');
+        assert_eq!(view[Vec2::new(-7, 5)], 'f');
+        assert_eq!(view.position(Vec2::new(4, 2)), Some(Vec2::new(1, 0)));
+    }
+
+    #[test]
+    fn test_clamp() {
+        let grid = letters(3, 2);
+        let view = grid.clamped();
+        assert_eq!(view[Vec2::new(-5, 0)], 'a');
+        assert_eq!(view[Vec2::new(10, 10)], 'f');
+        assert_eq!(view[Vec2::new(1, -3)], 'b');
+    }
+
+    #[test]
+    fn test_default() {
+        let grid = letters(3, 2);
+        let view = grid.with_default('.');
+        assert_eq!(view[Vec2::new(2, 1)], 'f');
+        assert_eq!(view[Vec2::new(3, 1)], '.');
+        assert_eq!(view.position(Vec2::new(3, 1)), None);
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid: Grid<char> = "".parse().unwrap();
+        assert_eq!(grid.wrapping().get(Vec2::new(0, 0)), None);
+        assert_eq!(grid.clamped().get(Vec2::new(1, 0)), None);
+        assert_eq!(grid.with_default('.').get(Vec2::new(0, 0)), Some(&'.'));
+    }
+}
@@ -1,3 +1,5 @@
 pub mod conversions;
 pub mod grid;
 pub mod point;
+pub mod search;
+pub mod tile;
@@ -6,6 +6,20 @@ pub const DOWN: Vec2 = Vec2 { x: 0, y: 1 };
 pub const LEFT: Vec2 = Vec2 { x: -1, y: 0 };
 pub const RIGHT: Vec2 = Vec2 { x: 1, y: 0 };
 
+/// The four orthogonal directions, in reading order.
+pub const DIRECTIONS4: [Vec2; 4] = [UP, LEFT, RIGHT, DOWN];
+/// The eight orthogonal and diagonal directions, in reading order.
+pub const DIRECTIONS8: [Vec2; 8] = [
+    Vec2 { x: -1, y: -1 },
+    UP,
+    Vec2 { x: 1, y: -1 },
+    LEFT,
+    RIGHT,
+    Vec2 { x: -1, y: 1 },
+    DOWN,
+    Vec2 { x: 1, y: 1 },
+];
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Vec2 {
     pub x: i32,
@@ -59,6 +73,21 @@ impl Vec2 {
     pub fn interpolate(&self, other: &Vec2) -> Vec2Iterator {
         Vec2Iterator::new(*self, *other)
     }
+
+    /// Returns the number of orthogonal steps between two points.
+    pub fn manhattan(&self, other: &Vec2) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Returns the orthogonally adjacent points, in reading order.
+    pub fn neighbors4(&self) -> [Vec2; 4] {
+        DIRECTIONS4.map(|d| *self + d)
+    }
+
+    /// Returns the orthogonally and diagonally adjacent points, in reading order.
+    pub fn neighbors8(&self) -> [Vec2; 8] {
+        DIRECTIONS8.map(|d| *self + d)
+    }
 }
 
 impl<T: Into<i32>> From<(T, T)> for Vec2 {
@@ -252,6 +281,13 @@ mod tests {
         assert_eq!(a.length(), 5.0);
     }
 
+    #[test]
+    fn test_vec2_manhattan() {
+        let a = Vec2::new(1, -2);
+        assert_eq!(a.manhattan(&Vec2::new(-3, 4)), 10);
+        assert_eq!(a.manhattan(&a), 0);
+    }
+
     #[test]
     fn test_vec2_clockwise() {
         let a = Vec2::new(1, 2);
@@ -277,4 +313,18 @@ mod tests {
         assert_eq!(iter.next(), Some(Vec2::new(3, 3)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn test_neighbors() {
+        let point = Vec2::new(2, 5);
+        assert_eq!(
+            point.neighbors4(),
+            [(2, 4), (1, 5), (3, 5), (2, 6)].map(Vec2::from)
+        );
+        let neighbors = point.neighbors8();
+        assert_eq!(neighbors[0], Vec2::new(1, 4));
+        assert_eq!(neighbors[7], Vec2::new(3, 6));
+        assert!(point.neighbors4().iter().all(|n| neighbors.contains(n)));
+        assert!(!neighbors.contains(&point));
+    }
 }
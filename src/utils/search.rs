@@ -0,0 +1,509 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+use super::{grid::Grid, point::Vec2};
+
+/// States reached by [`bfs`], [`dijkstra`] or [`a_star`] and the cheapest way to
+/// each of them.
+///
+/// [`bfs_all`] and [`dijkstra_all`] keep every way of equal cost, to count or
+/// visit all the cheapest paths.
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    /// First state found satisfying the goal, if any.
+    pub goal: Option<S>,
+    /// Every state satisfying the goal as cheaply as the first, when keeping ties.
+    pub goals: Vec<S>,
+    keep_ties: bool,
+    ids: HashMap<S, usize>,
+    states: Vec<S>,
+    distances: Vec<u64>,
+    predecessors: Vec<Vec<usize>>,
+}
+
+impl<S: Hash + Eq + Clone> Search<S> {
+    fn new(start: S, keep_ties: bool) -> Self {
+        Search {
+            goal: None,
+            goals: Vec::new(),
+            keep_ties,
+            ids: HashMap::from([(start.clone(), 0)]),
+            states: vec![start],
+            distances: vec![0],
+            predecessors: vec![Vec::new()],
+        }
+    }
+
+    /// Records a path to `state` through the state numbered `from`, returning the
+    /// number of `state` if that path is cheaper than any known.
+    fn relax(&mut self, state: S, from: usize, distance: u64) -> Option<usize> {
+        let id = match self.ids.entry(state) {
+            Entry::Occupied(entry) => {
+                let id = *entry.get();
+                if id == 0 {
+                    // the start has no predecessor, even through steps of no cost
+                    return None;
+                }
+                if distance == self.distances[id] && self.keep_ties {
+                    if !self.predecessors[id].contains(&from) {
+                        self.predecessors[id].push(from);
+                    }
+                    return None;
+                }
+                if distance >= self.distances[id] {
+                    return None;
+                }
+                self.distances[id] = distance;
+                self.predecessors[id] = vec![from];
+                id
+            }
+            Entry::Vacant(entry) => {
+                self.states.push(entry.key().clone());
+                entry.insert(self.states.len() - 1);
+                self.distances.push(distance);
+                self.predecessors.push(vec![from]);
+                self.states.len() - 1
+            }
+        };
+        Some(id)
+    }
+
+    /// Records that the state numbered `id` satisfies the goal, returning whether
+    /// the search is over.
+    fn reach_goal(&mut self, id: usize) -> bool {
+        self.goal.get_or_insert_with(|| self.states[id].clone());
+        self.goals.push(self.states[id].clone());
+        !self.keep_ties
+    }
+
+    /// Returns the cost of the cheapest path found to `state`.
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.ids.get(state).map(|&id| self.distances[id])
+    }
+
+    /// Returns the states from the start to `state`, both included.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        let mut id = *self.ids.get(state)?;
+        let mut path = vec![self.states[id].clone()];
+        while let Some(&previous) = self.predecessors[id].first() {
+            path.push(self.states[previous].clone());
+            id = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Returns the state before `state` on the cheapest path found to it, `None` for
+    /// the start or a state not reached.
+    pub fn predecessor(&self, state: &S) -> Option<&S> {
+        let id = *self.ids.get(state)?;
+        self.predecessors[id]
+            .first()
+            .map(|&previous| &self.states[previous])
+    }
+
+    pub fn goal_distance(&self) -> Option<u64> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.path(self.goal.as_ref()?)
+    }
+
+    /// Returns the reached states and their distances, in the order they were found.
+    pub fn reached(&self) -> impl Iterator<Item = (&S, u64)> {
+        self.states.iter().zip(self.distances.iter().copied())
+    }
+
+    /// Returns the number of cheapest paths from the start to `state`, 0 if it was
+    /// not reached, or `None` if the count overflows.
+    ///
+    /// Paths of equal cost are only all known after [`bfs_all`] or [`dijkstra_all`]
+    /// with steps of positive cost.
+    pub fn count_paths(&self, state: &S) -> Option<u128> {
+        let Some(&target) = self.ids.get(state) else {
+            return Some(0);
+        };
+        let on_paths = self.ancestors([target]);
+        // predecessors are nearer, so this order counts their paths first
+        let mut order = (0..self.states.len())
+            .filter(|&id| on_paths[id])
+            .collect::<Vec<_>>();
+        order.sort_by_key(|&id| self.distances[id]);
+        let mut counts = vec![0u128; self.states.len()];
+        counts[0] = 1;
+        for id in order {
+            for &previous in &self.predecessors[id] {
+                counts[id] = counts[id].checked_add(counts[previous])?;
+            }
+        }
+        Some(counts[target])
+    }
+
+    /// Returns the number of cheapest paths to the goals, see [`Search::count_paths`].
+    pub fn count_goal_paths(&self) -> Option<u128> {
+        self.goals.iter().try_fold(0u128, |total, goal| {
+            total.checked_add(self.count_paths(goal)?)
+        })
+    }
+
+    /// Returns the states on a cheapest path to any of `targets`, in the order they
+    /// were found.
+    pub fn on_paths<'a>(&'a self, targets: impl IntoIterator<Item = &'a S>) -> Vec<&'a S> {
+        let targets = targets
+            .into_iter()
+            .filter_map(|target| self.ids.get(target).copied());
+        let on_paths = self.ancestors(targets);
+        (0..self.states.len())
+            .filter(|&id| on_paths[id])
+            .map(|id| &self.states[id])
+            .collect()
+    }
+
+    /// Flags the states numbered `targets` and those on their cheapest paths.
+    fn ancestors(&self, targets: impl IntoIterator<Item = usize>) -> Vec<bool> {
+        let mut seen = vec![false; self.states.len()];
+        let mut stack = targets.into_iter().collect::<Vec<_>>();
+        while let Some(id) = stack.pop() {
+            if !seen[id] {
+                seen[id] = true;
+                stack.extend(&self.predecessors[id]);
+            }
+        }
+        seen
+    }
+
+    /// Marks in a `width` x `height` grid the positions of the states on a cheapest
+    /// path to a goal, `position` telling where a state stands.
+    pub fn goal_tiles<P>(&self, width: usize, height: usize, mut position: P) -> Grid<bool>
+    where
+        P: FnMut(&S) -> Vec2,
+    {
+        let mut tiles = Grid::from_fn(width, height, |_| false);
+        for state in self.on_paths(&self.goals) {
+            if let Some(tile) = tiles.get_mut(position(state)) {
+                *tile = true;
+            }
+        }
+        tiles
+    }
+}
+
+/// Explores the states reachable from `start`, each step to a successor costing 1,
+/// until one satisfies `goal`.
+///
+/// Pass `|_| false` as goal to explore every reachable state.
+pub fn bfs<S, I, N, G>(start: S, successors: N, goal: G) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    breadth_first(Search::new(start, false), successors, goal)
+}
+
+/// Like [`bfs`], keeping every cheapest path to every goal state as near as the
+/// first one.
+pub fn bfs_all<S, I, N, G>(start: S, successors: N, goal: G) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    breadth_first(Search::new(start, true), successors, goal)
+}
+
+fn breadth_first<S, I, N, G>(mut search: Search<S>, mut successors: N, mut goal: G) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    let mut queue = VecDeque::from([0]);
+    while let Some(id) = queue.pop_front() {
+        let distance = search.distances[id];
+        if search.goal_distance().is_some_and(|best| distance > best) {
+            break;
+        }
+        let state = search.states[id].clone();
+        if goal(&state) && search.reach_goal(id) {
+            break;
+        }
+        if search.goal.is_some() {
+            // only states as near as the goal are left to check
+            continue;
+        }
+        for next in successors(&state) {
+            queue.extend(search.relax(next, id, distance + 1));
+        }
+    }
+    search
+}
+
+/// Explores the states reachable from `start` cheapest first, until the cheapest
+/// path to a state satisfying `goal` is known.
+///
+/// `successors` yields each state reachable in one step with the cost of that step.
+pub fn dijkstra<S, I, N, G>(start: S, successors: N, goal: G) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    best_first(Search::new(start, false), successors, goal, |_| 0)
+}
+
+/// Like [`dijkstra`], keeping every cheapest path to every goal state as cheap as
+/// the first one.
+pub fn dijkstra_all<S, I, N, G>(start: S, successors: N, goal: G) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    best_first(Search::new(start, true), successors, goal, |_| 0)
+}
+
+/// Like [`dijkstra`], exploring first the states whose distance added to
+/// `heuristic` is the lowest.
+///
+/// The path found is the cheapest as long as `heuristic` never overestimates the
+/// cost left to reach a goal.
+pub fn a_star<S, I, N, G, H>(start: S, successors: N, goal: G, heuristic: H) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+    H: FnMut(&S) -> u64,
+{
+    best_first(Search::new(start, false), successors, goal, heuristic)
+}
+
+fn best_first<S, I, N, G, H>(
+    mut search: Search<S>,
+    mut successors: N,
+    mut goal: G,
+    mut heuristic: H,
+) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+    N: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+    H: FnMut(&S) -> u64,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&search.states[0]), 0, 0))]);
+    while let Some(Reverse((_, distance, id))) = queue.pop() {
+        if search.distances[id] != distance {
+            // a cheaper path to this state was queued since
+            continue;
+        }
+        if search.goal_distance().is_some_and(|best| distance > best) {
+            break;
+        }
+        let state = search.states[id].clone();
+        if goal(&state) && search.reach_goal(id) {
+            break;
+        }
+        if search.goal.is_some() {
+            continue;
+        }
+        for (next, cost) in successors(&state) {
+            let Some(through) = distance.checked_add(cost) else {
+                // no path costs more than u64::MAX
+                continue;
+            };
+            let estimate = through.saturating_add(heuristic(&next));
+            if let Some(next) = search.relax(next, id, through) {
+                queue.push(Reverse((estimate, through, next)));
+            }
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{
+        grid::Grid,
+        point::{Vec2, DIRECTIONS4, DOWN, RIGHT},
+    };
+
+    const MAZE: &str = "\
+#######
+#....E#
+#.###.#
+#S....#
+#######";
+
+    /// Moving forward costs 1 and turning a quarter costs 1000.
+    fn reindeer(grid: &Grid<char>, &(point, heading): &(Vec2, Vec2)) -> Vec<((Vec2, Vec2), u64)> {
+        let mut moves = vec![
+            ((point, heading.clockwise()), 1000),
+            ((point, heading.anti_clockwise()), 1000),
+        ];
+        if grid.get(point + heading) != Some(&'#') {
+            moves.push(((point + heading, heading), 1));
+        }
+        moves
+    }
+
+    #[test]
+    fn test_dijkstra_with_heading() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let (start, end) = (Vec2::new(1, 3), Vec2::new(5, 1));
+        let search = dijkstra(
+            (start, RIGHT),
+            |state| reindeer(&grid, state),
+            |&(point, _)| point == end,
+        );
+        assert_eq!(search.goal_distance(), Some(1006));
+        let path = search.goal_path().unwrap();
+        assert_eq!(path.first(), Some(&(start, RIGHT)));
+        assert_eq!(path.last().map(|s| s.0), Some(end));
+        assert_eq!(path.len(), 8);
+    }
+
+    #[test]
+    fn test_a_star() {
+        let grid: Grid<char> = MAZE.parse().unwrap();
+        let (start, end) = (Vec2::new(1, 3), Vec2::new(5, 1));
+        let search = a_star(
+            (start, RIGHT),
+            |state| reindeer(&grid, state),
+            |&(point, _)| point == end,
+            |&(point, _)| point.manhattan(&end) as u64,
+        );
+        assert_eq!(search.goal_distance(), Some(1006));
+
+        // on an open plane, the heuristic keeps the search along the straight line
+        let plane = |p: &Vec2| p.neighbors4().map(|n| (n, 1));
+        let goal = Vec2::new(10, 0);
+        let guided = a_star(
+            Vec2::default(),
+            plane,
+            |&p| p == goal,
+            |p| p.manhattan(&goal) as u64,
+        );
+        let blind = dijkstra(Vec2::default(), plane, |&p| p == goal);
+        assert_eq!(guided.goal_distance(), Some(10));
+        assert_eq!(blind.goal_distance(), Some(10));
+        assert!(guided.reached().count() < 40);
+        assert!(blind.reached().count() > 200);
+    }
+
+    #[test]
+    fn test_bfs_with_keys() {
+        // the door needs the key lying behind the start
+        let grid: Grid<char> = "k.S.D.E".parse().unwrap();
+        let grid = &grid;
+        let successors = |&(point, key): &(Vec2, bool)| {
+            point
+                .neighbors4()
+                .into_iter()
+                .filter(move |&next| match grid.get(next) {
+                    Some('D') => key,
+                    Some(_) => true,
+                    None => false,
+                })
+                .map(|next| (next, key || grid[next] == 'k'))
+                .collect::<Vec<_>>()
+        };
+        let search = bfs((Vec2::new(2, 0), false), successors, |&(point, _)| {
+            grid[point] == 'E'
+        });
+        assert_eq!(search.goal, Some((Vec2::new(6, 0), true)));
+        assert_eq!(search.goal_distance(), Some(8));
+        assert_eq!(search.goal_path().unwrap().len(), 9);
+    }
+
+    #[test]
+    fn test_no_goal() {
+        let search = bfs(0, |&n| (n < 5).then_some(n + 1), |_| false);
+        assert_eq!(search.goal, None);
+        assert_eq!(search.goal_path(), None);
+        assert_eq!(search.distance(&5), Some(5));
+        assert_eq!(search.path(&3), Some(vec![0, 1, 2, 3]));
+        assert_eq!(search.distance(&6), None);
+    }
+
+    #[test]
+    fn test_zero_cost_cycle() {
+        // 0 and 1 lead to each other for free, 2 is one step further
+        let successors = |&n: &u8| match n {
+            0 => vec![(1, 0)],
+            1 => vec![(0, 0), (2, 1)],
+            _ => vec![(1, 0)],
+        };
+        let search = dijkstra_all(0, successors, |&n| n == 2);
+        assert_eq!(search.goal_distance(), Some(1));
+        assert_eq!(search.path(&0), Some(vec![0]));
+        assert_eq!(search.goal_path(), Some(vec![0, 1, 2]));
+        assert_eq!(search.on_paths([&2]), [&0, &1, &2]);
+    }
+
+    /// Moves down and right, as far as `size` in both directions.
+    fn lattice(size: i32) -> impl FnMut(&Vec2) -> Vec<Vec2> {
+        move |&p| {
+            [p + RIGHT, p + DOWN]
+                .into_iter()
+                .filter(|n| n.x <= size && n.y <= size)
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_count_paths() {
+        let corner = Vec2::new(3, 3);
+        let search = bfs_all(Vec2::default(), lattice(3), |&p| p == corner);
+        assert_eq!(search.count_paths(&corner), Some(20));
+        assert_eq!(search.count_paths(&Vec2::new(1, 2)), Some(3));
+        assert_eq!(search.count_paths(&Vec2::new(9, 9)), Some(0));
+        let single = bfs(Vec2::default(), lattice(3), |&p| p == corner);
+        assert_eq!(single.count_paths(&corner), Some(1));
+    }
+
+    #[test]
+    fn test_count_overflow() {
+        let corner = Vec2::new(60, 60);
+        let search = bfs_all(Vec2::default(), lattice(70), |_| false);
+        // 120 choose 60 still fits, 140 choose 70 does not
+        assert_eq!(
+            search.count_paths(&corner),
+            Some(96614908840363322603893139521372656)
+        );
+        assert_eq!(search.count_paths(&Vec2::new(70, 70)), None);
+    }
+
+    #[test]
+    fn test_all_goals_and_tiles() {
+        // the end is reached round either side of the wall, facing two ways
+        let grid: Grid<char> = "S..\n.#.\n..E\n...".parse().unwrap();
+        let end = Vec2::new(2, 2);
+        let successors = |&(point, _): &(Vec2, Vec2)| {
+            DIRECTIONS4
+                .into_iter()
+                .filter(|&d| grid.get(point + d).is_some_and(|&c| c != '#'))
+                .map(|d| ((point + d, d), 1))
+                .collect::<Vec<_>>()
+        };
+        let search = dijkstra_all((Vec2::default(), RIGHT), successors, |s| s.0 == end);
+        assert_eq!(search.goal_distance(), Some(4));
+        assert_eq!(search.goals.len(), 2);
+        assert_eq!(search.count_goal_paths(), Some(2));
+        let tiles = search.goal_tiles(grid.width, grid.height, |s| s.0);
+        assert_eq!(tiles.iter_positions().filter(|&p| tiles[p]).count(), 8);
+        assert!(!tiles[Vec2::new(1, 3)]);
+        assert_eq!(search.on_paths([&(end, DOWN)]).len(), 5);
+    }
+}
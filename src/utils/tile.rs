@@ -0,0 +1,106 @@
+/// A grid cell written as a single character.
+///
+/// Grids of tiles parse with `str::parse` and print back through `Display`.
+/// Enums mapping each variant to a character implement it with [`tiles!`](crate::tiles).
+pub trait Tile: Sized {
+    fn from_char(c: char) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+}
+
+impl Tile for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+/// Declares an enum whose variants are written as characters, implementing
+/// [`Tile`] and `Display` for it.
+///
+/// ```ignore
+/// tiles! {
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     pub enum Cell {
+///         Empty = '.',
+///         Obstacle = '#',
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! tiles {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $c:literal),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),+
+        }
+
+        impl $crate::utils::tile::Tile for $name {
+            fn from_char(c: char) -> Option<Self> {
+                match c {
+                    $($c => Some($name::$variant),)+
+                    _ => None,
+                }
+            }
+
+            fn to_char(&self) -> char {
+                match self {
+                    $($name::$variant => $c),+
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                use std::fmt::Write;
+                f.write_char($crate::utils::tile::Tile::to_char(self))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::{grid::Grid, point::Vec2};
+
+    tiles! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Cell {
+            Empty = '.',
+            /// Blocks the way.
+            Wall = '#',
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert_eq!(Cell::from_char('#'), Some(Cell::Wall));
+        assert_eq!(Cell::from_char('x'), None);
+        assert_eq!(Cell::Empty.to_char(), '.');
+        assert_eq!(Cell::Wall.to_string(), "#");
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid: Grid<Cell> = "#.\n.#\n".parse().unwrap();
+        assert_eq!(grid[Vec2::new(1, 0)], Cell::Empty);
+        assert_eq!(grid[Vec2::new(1, 1)], Cell::Wall);
+        assert_eq!(grid.to_string(), "#.\n.#\n");
+    }
+
+    #[test]
+    fn test_unknown_tile() {
+        let error = "#.\n.x\n".parse::<Grid<Cell>>().unwrap_err();
+        assert_eq!(error.reason, "unknown tile 'x'");
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    answer::Answer,
+    error::Result,
+    solution::Solution,
+    tiles,
+    utils::{grid::*, point::*},
+};
+
+tiles! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Cell {
+        Open = '.',
+        Wall = '#',
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<Cell>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, map: &Self::Input) -> Result<Answer> {
+        Ok(map
+            .iter_positions()
+            .filter(|&p| map[p] == Cell::Wall)
+            .count()
+            .into())
+    }
+
+    fn part2(&self, _map: &Self::Input) -> Result<Answer> {
+        Ok(0.into())
+    }
+}
+
+fn find(map: &Grid<Cell>, target: Cell) -> Option<Vec2> {
+    map.iter_positions().find(|&p| map[p] == target)
+}
@@ -0,0 +1,43 @@
+use std::str::FromStr;
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    solution::Solution,
+    utils::conversions::{parse_at, Parser},
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Line>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse_lines()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.iter().map(|l| l.values.len()).sum::<usize>().into())
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<Answer> {
+        Ok(0.into())
+    }
+}
+
+#[derive(Debug)]
+pub struct Line {
+    values: Vec<i64>,
+}
+
+impl FromStr for Line {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let values = s
+            .split_whitespace()
+            .map(|x| parse_at(s, x))
+            .collect::<Result<_>>()?;
+        Ok(Line { values })
+    }
+}
@@ -0,0 +1,44 @@
+use std::str::FromStr;
+
+use crate::{
+    answer::Answer,
+    error::{Error, Result},
+    solution::Solution,
+};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Sections;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.first.len().into())
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(input.second.len().into())
+    }
+}
+
+pub struct Sections {
+    first: Vec<String>,
+    second: Vec<String>,
+}
+
+impl FromStr for Sections {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (first, second) = s
+            .split_once("\n\n")
+            .ok_or_else(|| Error::new("expected two sections split by a blank line"))?;
+        Ok(Sections {
+            first: first.lines().map(str::to_owned).collect(),
+            second: second.lines().map(str::to_owned).collect(),
+        })
+    }
+}
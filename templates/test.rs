@@ -0,0 +1,13 @@
+use advent_of_code::{
+    answers::check, days::{{module}}::Day, error::Result, examples::check_saved,
+};
+
+#[test]
+fn examples() -> Result<()> {
+    check_saved(&Day, {{year}}, {{day}})
+}
+
+#[test]
+fn run() -> Result<()> {
+    check({{year}}, {{day}})
+}
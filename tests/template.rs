@@ -1,14 +0,0 @@
-pub(crate) fn solve() -> Result<(u32, u32), ()> {
-    let mut _input = include_str!("../inputs/template");
-
-    let solution_1 = 0;
-    let solution_2 = 0;
-
-    Ok((solution_1, solution_2))
-}
-
-#[test]
-fn run() -> Result<(), ()> {
-    assert_eq!(solve()?, (0, 0));
-    Ok(())
-}